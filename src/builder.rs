@@ -0,0 +1,68 @@
+//! Building values on the heap before pinning them.
+
+use std::pin::Pin;
+
+/// Used for assembling a value step by step before pinning it.
+/// The value is allocated on the heap by `new`, and stays in that allocation when it gets pinned by `build`,
+/// so it is never moved after it has been assembled.
+///
+/// Until `build` is called the value is not pinned, and `set` may mutate or replace it freely.
+/// Once `build` has been called, the value is pinned and may no longer be moved, unless it is `Unpin`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::marker::PhantomPinned;
+/// use std::pin::Pin;
+/// use pinpoint::PinBuilder;
+///
+/// #[derive(Default)]
+/// struct Node {
+///     name: String,
+///     len: usize,
+///     _pin: PhantomPinned,
+/// }
+///
+/// let node: Pin<Box<Node>> = PinBuilder::new()
+///     .set(|node: &mut Node| node.name.push_str("root"))
+///     .set(|node| node.len = node.name.len())
+///     .build();
+///
+/// assert_eq!(node.name, "root");
+/// assert_eq!(node.len, 4);
+/// ```
+#[derive(Debug, Default)]
+pub struct PinBuilder<T> {
+    value: Box<T>,
+}
+
+impl<T: Default> PinBuilder<T> {
+    /// Creates a builder holding the default value of `T`.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_value(T::default())
+    }
+}
+
+impl<T> PinBuilder<T> {
+    /// Creates a builder holding `value`.
+    #[inline]
+    pub fn from_value(value: T) -> Self {
+        Self {
+            value: Box::new(value),
+        }
+    }
+
+    /// Mutates the value that is being built.
+    #[inline]
+    pub fn set<F: FnOnce(&mut T)>(mut self, f: F) -> Self {
+        f(&mut self.value);
+        self
+    }
+
+    /// Pins the value in the allocation it was built in.
+    #[inline]
+    pub fn build(self) -> Pin<Box<T>> {
+        Box::into_pin(self.value)
+    }
+}
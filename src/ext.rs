@@ -0,0 +1,1386 @@
+use std::any::Any;
+use std::borrow::Borrow;
+use std::cell::{OnceCell, Ref, RefCell, RefMut};
+use std::collections::{BTreeMap, BinaryHeap, HashMap, VecDeque};
+use std::ffi::{OsStr, OsString};
+use std::hash::{BuildHasher, Hash};
+use std::io::BufReader;
+use std::mem;
+use std::ops::{Deref, DerefMut, RangeBounds};
+use std::path::Path;
+use std::pin::Pin;
+use std::rc::{self, Rc};
+use std::sync::{self, Arc, LockResult, Mutex, MutexGuard, OnceLock, PoisonError};
+
+/// Used for pinning the contents of map types.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::collections::BTreeMap;
+/// use std::pin::Pin;
+/// use pinpoint::MapPinExt;
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(2, "b");
+/// map.insert(1, "a");
+///
+/// let entries: Pin<Vec<(u32, &str)>> = map.into_pin_entries();
+/// assert_eq!(*entries, [(1, "a"), (2, "b")]);
+/// ```
+pub trait MapPinExt<K, V> {
+    /// Collects the entries of the map into a pinned `Vec`.
+    fn into_pin_entries(self) -> Pin<Vec<(K, V)>>
+    where
+        K: Unpin;
+
+    /// Collects the entries of the map into a pinned boxed slice.
+    /// The entries are in the iteration order of the map, which is unspecified for `HashMap` and sorted by key for `BTreeMap`.
+    fn into_pin_entries_boxed(self) -> Pin<Box<[(K, V)]>>
+    where
+        K: Unpin;
+
+    /// Returns an iterator over the values as pinned mutable references.
+    fn pin_values_mut<'a>(&'a mut self) -> impl Iterator<Item = Pin<&'a mut V>>
+    where
+        V: 'a;
+}
+
+/// Used for pinning the value of a single key in map types.
+/// The key can be given as any borrowed form of the map's key type, like [`HashMap::get_mut`].
+///
+/// [`HashMap::get_mut`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html#method.get_mut
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::collections::HashMap;
+/// use pinpoint::MapKeyPinExt;
+///
+/// let mut map = HashMap::new();
+/// map.insert(String::from("a"), 1);
+///
+/// *map.pin_value_mut("a").unwrap() += 1;
+/// assert_eq!(map["a"], 2);
+/// ```
+pub trait MapKeyPinExt<Q: ?Sized, V> {
+    /// Returns the value corresponding to the key as a pinned mutable reference.
+    fn pin_value_mut(&mut self, key: &Q) -> Option<Pin<&mut V>>;
+}
+
+/// Used for splitting slices into pinned sub-slices.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::SlicePinExt;
+///
+/// let v = vec![1, 2, 3, 4, 5];
+///
+/// let chunks: Vec<Pin<&[u32]>> = v.into_pin_chunks(2).collect();
+/// assert_eq!(chunks.len(), 3);
+/// ```
+pub trait SlicePinExt<'a, T: 'a> {
+    /// Returns an iterator over pinned chunks of `n` elements, like [`slice::chunks`].
+    /// The last chunk may be shorter than `n`.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    ///
+    /// [`slice::chunks`]: https://doc.rust-lang.org/std/primitive.slice.html#method.chunks
+    fn into_pin_chunks(self, n: usize) -> impl Iterator<Item = Pin<&'a [T]>>;
+
+    /// Splits the slice into two pinned halves at `mid`, like [`slice::split_at`].
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    ///
+    /// [`slice::split_at`]: https://doc.rust-lang.org/std/primitive.slice.html#method.split_at
+    fn into_pin_split_at(self, mid: usize) -> (Pin<&'a [T]>, Pin<&'a [T]>);
+
+    /// Returns an iterator over overlapping pinned windows of `size` elements, like [`slice::windows`].
+    /// If the slice is shorter than `size`, the iterator yields nothing.
+    ///
+    /// # Panics
+    /// Panics if `size` is 0.
+    ///
+    /// [`slice::windows`]: https://doc.rust-lang.org/std/primitive.slice.html#method.windows
+    fn into_pin_windows(self, size: usize) -> impl Iterator<Item = Pin<&'a [T]>>;
+}
+
+/// Used for pinning the elements and sub-slices of mutable slices.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use pinpoint::SliceMutPinExt;
+///
+/// let mut v = vec![1, 2, 3, 4];
+///
+/// let (mut left, mut right) = v.into_pin_split_at_mut(2);
+/// left[0] = 0;
+/// right[0] = 0;
+///
+/// assert_eq!(v, [0, 2, 0, 4]);
+/// ```
+pub trait SliceMutPinExt<'a, T: 'a> {
+    /// Splits the slice into two pinned mutable halves at `mid`, like [`slice::split_at_mut`].
+    ///
+    /// # Panics
+    /// Panics if `mid > len`.
+    ///
+    /// [`slice::split_at_mut`]: https://doc.rust-lang.org/std/primitive.slice.html#method.split_at_mut
+    fn into_pin_split_at_mut(self, mid: usize) -> (Pin<&'a mut [T]>, Pin<&'a mut [T]>);
+
+    /// Splits the slice into two pinned mutable halves.
+    /// For an odd length, the second half gets the extra element.
+    fn into_pin_halves_mut(self) -> (Pin<&'a mut [T]>, Pin<&'a mut [T]>);
+
+    /// Returns an iterator over pinned mutable references to the elements.
+    fn pin_iter_mut(self) -> impl Iterator<Item = Pin<&'a mut T>>;
+
+    /// Returns the element at index `i` as a pinned mutable reference, or `None` if `i` is out of bounds.
+    fn pin_get_mut(self, i: usize) -> Option<Pin<&'a mut T>>;
+
+    /// Returns an iterator over pinned mutable arrays of `N` elements, like [`slice::as_chunks_mut`].
+    /// The remainder that is shorter than `N` is not yielded, use `pin_array_chunks_remainder` to get it.
+    ///
+    /// # Panics
+    /// Panics if `N` is 0.
+    ///
+    /// [`slice::as_chunks_mut`]: https://doc.rust-lang.org/std/primitive.slice.html#method.as_chunks_mut
+    fn pin_array_chunks<const N: usize>(self) -> impl Iterator<Item = Pin<&'a mut [T; N]>>;
+
+    /// Returns the last `len % N` elements as a pinned mutable slice, which `pin_array_chunks` leaves out.
+    ///
+    /// # Panics
+    /// Panics if `N` is 0.
+    fn pin_array_chunks_remainder<const N: usize>(self) -> Pin<&'a mut [T]>;
+}
+
+/// Used for pinning the `Ok` value of a `Result`, leaving the `Err` value untouched.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::ResultPinExt;
+///
+/// let mut n = 0;
+/// let res: Result<&mut u32, ()> = Ok(&mut n);
+///
+/// let mut pin: Pin<&mut u32> = res.into_pin_ok().unwrap();
+/// *pin = 1;
+/// assert_eq!(n, 1);
+/// ```
+pub trait ResultPinExt<P, E> {
+    /// Pins the `Ok` value.
+    fn into_pin_ok(self) -> Result<Pin<P>, E>;
+}
+
+/// Used for searching pinned byte slices.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::{IntoPin, PinSliceExt};
+///
+/// let pin: Pin<&[u8]> = "hello".into_pin();
+///
+/// assert_eq!(pin.memchr_pin(b'l'), Some(2));
+/// ```
+#[cfg(feature = "memchr")]
+pub trait PinSliceExt {
+    /// Returns the index of the first occurrence of `byte`.
+    fn memchr_pin(&self, byte: u8) -> Option<usize>;
+}
+
+/// Used for pinning the guards of a `RefCell`.
+/// This also works through the smart pointers around it, like `Rc<RefCell<T>>`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::cell::RefCell;
+/// use std::pin::Pin;
+/// use std::rc::Rc;
+/// use pinpoint::RefCellPinExt;
+///
+/// let rc = Rc::new(RefCell::new(1));
+///
+/// *rc.pin_borrow_mut() += 1;
+/// assert_eq!(*rc.pin_borrow(), 2);
+/// ```
+pub trait RefCellPinExt<T: ?Sized> {
+    /// Immutably borrows the value, and pins the guard.
+    ///
+    /// # Panics
+    /// Panics if the value is currently mutably borrowed, like [`RefCell::borrow`].
+    ///
+    /// [`RefCell::borrow`]: https://doc.rust-lang.org/std/cell/struct.RefCell.html#method.borrow
+    fn pin_borrow(&self) -> Pin<Ref<'_, T>>;
+
+    /// Mutably borrows the value, and pins the guard.
+    ///
+    /// # Panics
+    /// Panics if the value is currently borrowed, like [`RefCell::borrow_mut`].
+    ///
+    /// [`RefCell::borrow_mut`]: https://doc.rust-lang.org/std/cell/struct.RefCell.html#method.borrow_mut
+    fn pin_borrow_mut(&self) -> Pin<RefMut<'_, T>>;
+}
+
+/// Used for pinning the contents of once-initialized cells.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use std::sync::OnceLock;
+/// use pinpoint::OncePinExt;
+///
+/// let cell = OnceLock::new();
+/// assert!(cell.pin_get().is_none());
+///
+/// cell.set(1).unwrap();
+/// let pin: Pin<&u32> = cell.pin_get().unwrap();
+/// assert_eq!(*pin, 1);
+/// ```
+pub trait OncePinExt<T> {
+    /// Returns the value as a pinned reference, or `None` if the cell is uninitialized.
+    fn pin_get(&self) -> Option<Pin<&T>>;
+}
+
+/// Used for pinning the target of any `Deref` type, without implementing `IntoPin` for it.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::DerefPinExt;
+///
+/// let mut b: Box<[u8]> = Box::new([1, 2, 3]);
+///
+/// b.pin_deref_mut().reverse();
+/// assert_eq!(*b.pin_deref(), [3, 2, 1]);
+/// ```
+pub trait DerefPinExt: Deref {
+    /// Pins a reference to the target.
+    fn pin_deref(&self) -> Pin<&Self::Target>;
+
+    /// Pins a mutable reference to the target.
+    fn pin_deref_mut(&mut self) -> Pin<&mut Self::Target>
+    where
+        Self: DerefMut;
+}
+
+/// Used for pinning the rows of nested `Vec`s, like jagged matrices.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::RowsPinExt;
+///
+/// let matrix = vec![vec![1, 2], vec![], vec![3]];
+///
+/// let rows: Vec<Pin<&[u32]>> = matrix.into_pin_rows().collect();
+/// assert_eq!(rows.len(), 3);
+/// ```
+pub trait RowsPinExt<'a, T: 'a> {
+    /// Returns an iterator over the rows as pinned slices. Empty rows yield empty slices.
+    fn into_pin_rows(self) -> impl Iterator<Item = Pin<&'a [T]>>;
+}
+
+/// Used for reborrowing pinned mutable references as pinned references to their `AsMut` targets.
+/// For plain reborrows, use [`Pin::as_ref`] and [`Pin::as_mut`], which do not need `IntoPin` either.
+///
+/// [`Pin::as_ref`]: https://doc.rust-lang.org/std/pin/struct.Pin.html#method.as_ref
+/// [`Pin::as_mut`]: https://doc.rust-lang.org/std/pin/struct.Pin.html#method.as_mut
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::{IntoPin, PinMutExt};
+///
+/// fn bump(mut p: Pin<&mut [u32]>) {
+///     p[0] += 1;
+/// }
+///
+/// let mut v = vec![1, 2, 3];
+/// let mut pin: Pin<&mut Vec<u32>> = (&mut v).into_pin();
+///
+/// for _ in 0..3 {
+///     bump(pin.pin_as_mut());
+/// }
+/// assert_eq!(pin.as_ref().len(), 3);
+/// assert_eq!(v, [4, 2, 3]);
+/// ```
+pub trait PinMutExt<T: ?Sized> {
+    /// Reborrows as a pinned mutable reference to the target of `T`'s `AsMut` impl.
+    fn pin_as_mut<U: Unpin + ?Sized>(&mut self) -> Pin<&mut U>
+    where
+        T: AsMut<U>;
+}
+
+/// Used for pinning each pointer in a tuple separately, up to a tuple of 4 elements.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::TuplePinExt;
+///
+/// let (a, mut b) = (1, String::from("b"));
+///
+/// let (a, mut b): (Pin<&u32>, Pin<&mut String>) = (&a, &mut b).into_pin_tuple();
+/// b.push_str("c");
+///
+/// assert_eq!(*a, 1);
+/// assert_eq!(&*b, "bc");
+/// ```
+pub trait TuplePinExt {
+    /// The tuple of pins.
+    type Pinned;
+
+    /// Pins each element of the tuple.
+    fn into_pin_tuple(self) -> Self::Pinned;
+}
+
+/// Used for pinning the contents of a `BinaryHeap`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::collections::BinaryHeap;
+/// use std::pin::Pin;
+/// use pinpoint::HeapPinExt;
+///
+/// let heap = BinaryHeap::from(vec![3, 1, 2]);
+///
+/// let sorted: Pin<Vec<u32>> = heap.pin_into_sorted_vec();
+/// assert_eq!(*sorted, [1, 2, 3]);
+/// ```
+pub trait HeapPinExt<T> {
+    /// Consumes the heap and returns a pinned `Vec` in ascending order, like [`BinaryHeap::into_sorted_vec`].
+    ///
+    /// [`BinaryHeap::into_sorted_vec`]: https://doc.rust-lang.org/std/collections/struct.BinaryHeap.html#method.into_sorted_vec
+    fn pin_into_sorted_vec(self) -> Pin<Vec<T>>;
+}
+
+/// Used for pinning multi-dimensional arrays as a single flat slice.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::FlatPinExt;
+///
+/// let matrix = [[1, 2, 3], [4, 5, 6]];
+///
+/// let flat: Pin<&[u32]> = matrix.into_pin_flat();
+/// assert_eq!(*flat, [1, 2, 3, 4, 5, 6]);
+/// ```
+pub trait FlatPinExt<'a, T: 'a> {
+    /// Flattens the rows into one pinned slice, like [`slice::as_flattened`].
+    ///
+    /// [`slice::as_flattened`]: https://doc.rust-lang.org/std/primitive.slice.html#method.as_flattened
+    fn into_pin_flat(self) -> Pin<&'a [T]>;
+}
+
+/// Used for pinning the parts of a `Path`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::ffi::OsStr;
+/// use std::path::Path;
+/// use std::pin::Pin;
+/// use pinpoint::PathPinExt;
+///
+/// let components: Vec<Pin<&OsStr>> = Path::new("a/b").pin_components().collect();
+/// assert_eq!(components.len(), 2);
+/// ```
+pub trait PathPinExt {
+    /// Returns an iterator over the components of the path as pinned `OsStr`s, like [`Path::components`].
+    /// The root and any prefix are yielded as well.
+    ///
+    /// [`Path::components`]: https://doc.rust-lang.org/std/path/struct.Path.html#method.components
+    fn pin_components(&self) -> impl Iterator<Item = Pin<&OsStr>>;
+}
+
+/// Used for pinning the contents of a `Vec`, while keeping the `Vec` around.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::VecPinExt;
+///
+/// let mut v = vec![1, 2, 3];
+///
+/// let snapshot: Pin<Box<[u32]>> = v.drain_into_pin();
+/// assert_eq!(*snapshot, [1, 2, 3]);
+/// assert!(v.is_empty());
+/// ```
+pub trait VecPinExt<T> {
+    /// Moves all elements into a pinned boxed slice, leaving the `Vec` empty with its capacity intact.
+    fn drain_into_pin(&mut self) -> Pin<Box<[T]>>;
+
+    /// Takes the `Vec` out by value and pins it, leaving an empty `Vec` without capacity behind.
+    /// Unlike `drain_into_pin`, this does not allocate.
+    /// # Examples
+    /// ```
+    ///
+    /// extern crate pinpoint;
+    /// use std::pin::Pin;
+    /// use pinpoint::VecPinExt;
+    ///
+    /// let mut buffer = vec![1, 2, 3];
+    ///
+    /// let batch: Pin<Vec<u32>> = buffer.take_pin();
+    /// assert_eq!(*batch, [1, 2, 3]);
+    /// assert_eq!(buffer.capacity(), 0);
+    /// ```
+    fn take_pin(&mut self) -> Pin<Vec<T>>;
+}
+
+/// Used for splitting pinned mutable slices into pinned mutable sub-slices.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::{IntoPin, PinSliceMutExt};
+///
+/// let mut v = vec![1, 2, 3, 4, 5];
+/// let pin: Pin<&mut [u32]> = (&mut v).into_pin();
+///
+/// for mut chunk in pin.into_pin_chunks_mut(2) {
+///     chunk[0] = 0;
+/// }
+/// assert_eq!(v, [0, 2, 0, 4, 0]);
+/// ```
+pub trait PinSliceMutExt<'a, T: 'a> {
+    /// Returns an iterator over pinned mutable chunks of `n` elements, like [`slice::chunks_mut`].
+    /// The last chunk may be shorter than `n`.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    ///
+    /// [`slice::chunks_mut`]: https://doc.rust-lang.org/std/primitive.slice.html#method.chunks_mut
+    fn into_pin_chunks_mut(self, n: usize) -> impl Iterator<Item = Pin<&'a mut [T]>>;
+}
+
+/// Used for pinning the guard of a `Mutex`.
+/// This also works through the smart pointers around it, like `Arc<Mutex<T>>`.
+/// For `Rc<RefCell<T>>`, see [`RefCellPinExt`].
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::sync::{Arc, Mutex};
+/// use pinpoint::MutexPinExt;
+///
+/// let shared = Arc::new(Mutex::new(1));
+///
+/// *shared.pin_lock().unwrap() += 1;
+/// assert_eq!(*shared.pin_lock().unwrap(), 2);
+/// ```
+pub trait MutexPinExt<T: ?Sized> {
+    /// Locks the mutex, and pins the guard.
+    /// The pinned guard can be kept in a binding, and reborrowed with [`Pin::as_mut`] and [`Pin::as_ref`] while the lock is held.
+    ///
+    /// [`Pin::as_mut`]: https://doc.rust-lang.org/std/pin/struct.Pin.html#method.as_mut
+    /// [`Pin::as_ref`]: https://doc.rust-lang.org/std/pin/struct.Pin.html#method.as_ref
+    ///
+    /// # Errors
+    /// If the mutex is poisoned, the pinned guard is returned inside the error, like [`Mutex::lock`].
+    ///
+    /// [`Mutex::lock`]: https://doc.rust-lang.org/std/sync/struct.Mutex.html#method.lock
+    /// # Examples
+    /// ```
+    ///
+    /// extern crate pinpoint;
+    /// use std::pin::Pin;
+    /// use std::sync::{Mutex, PoisonError};
+    /// use pinpoint::MutexPinExt;
+    ///
+    /// fn push(mut v: Pin<&mut Vec<u32>>, n: u32) {
+    ///     v.push(n);
+    /// }
+    ///
+    /// let m = Mutex::new(vec![1]);
+    ///
+    /// let mut g = m.pin_lock().unwrap_or_else(PoisonError::into_inner);
+    /// push(g.as_mut(), 2);
+    /// push(g.as_mut(), 3);
+    /// assert_eq!(g.as_ref().len(), 3);
+    /// ```
+    fn pin_lock(&self) -> LockResult<Pin<MutexGuard<'_, T>>>;
+}
+
+/// Used for pinning the values in slices of key-value pairs.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use pinpoint::PairsPinExt;
+///
+/// let mut pairs = vec![("a", 1), ("b", 2)];
+///
+/// for mut value in pairs.pin_values_mut() {
+///     *value *= 10;
+/// }
+/// assert_eq!(pairs, [("a", 10), ("b", 20)]);
+/// ```
+pub trait PairsPinExt<'a, K: 'a, V: 'a> {
+    /// Returns an iterator over the second element of each pair, as pinned mutable references.
+    fn pin_values_mut(self) -> impl Iterator<Item = Pin<&'a mut V>>;
+}
+
+/// Used for pinning the target of any `AsRef` impl.
+///
+/// A blanket `IntoPin<&T>` impl for every `&S` where `S: AsRef<T>` would overlap with the other reference impls of `IntoPin`,
+/// such as `&T` to `Pin<&T>`. That is why this is a separate trait. The target usually needs to be named, because many types implement `AsRef` for several targets.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::AsRefPinExt;
+///
+/// let s = String::from("hello");
+///
+/// let bytes: Pin<&[u8]> = s.pin_as_ref();
+/// assert_eq!(*bytes, *b"hello");
+/// ```
+/// Slice-like containers, such as `Vec<T>`, `Box<[T]>` and `[T; N]`, can be pinned as a slice without naming the target:
+/// ```
+///
+/// extern crate pinpoint;
+/// use pinpoint::AsRefPinExt;
+///
+/// let mut a = [3, 2, 1];
+/// let mut v = vec![3, 2, 1];
+///
+/// a.as_pin_slice_mut().reverse();
+/// v.as_pin_slice_mut().reverse();
+///
+/// assert_eq!(*a.as_pin_slice(), *v.as_pin_slice());
+/// ```
+pub trait AsRefPinExt {
+    /// Pins the reference returned by `AsRef::as_ref`.
+    fn pin_as_ref<T: Unpin + ?Sized>(&self) -> Pin<&T>
+    where
+        Self: AsRef<T>;
+
+    /// Pins the contents as a shared slice.
+    fn as_pin_slice<T: Unpin>(&self) -> Pin<&[T]>
+    where
+        Self: AsRef<[T]>;
+
+    /// Pins the contents as a mutable slice.
+    fn as_pin_slice_mut<T: Unpin>(&mut self) -> Pin<&mut [T]>
+    where
+        Self: AsMut<[T]>;
+}
+
+/// Used for pinning the reader inside a `BufReader`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::io::{BufReader, Cursor};
+/// use std::pin::Pin;
+/// use pinpoint::BufReaderPinExt;
+///
+/// let reader = BufReader::new(Cursor::new(vec![1, 2, 3]));
+///
+/// let inner: Pin<Box<Cursor<Vec<u8>>>> = reader.into_pin_inner();
+/// assert_eq!(inner.get_ref(), &[1, 2, 3]);
+/// ```
+pub trait BufReaderPinExt<R> {
+    /// Unwraps the reader, like [`BufReader::into_inner`], and pins it in a `Box`.
+    /// Any data left in the buffer is lost.
+    ///
+    /// [`BufReader::into_inner`]: https://doc.rust-lang.org/std/io/struct.BufReader.html#method.into_inner
+    fn into_pin_inner(self) -> Pin<Box<R>>;
+}
+
+/// Used for upgrading weak pointers into pinned strong pointers.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use std::sync::Arc;
+/// use pinpoint::WeakPinExt;
+///
+/// let strong = Arc::new(1);
+/// let weak = Arc::downgrade(&strong);
+///
+/// let pin: Pin<Arc<u32>> = weak.upgrade_pin().unwrap();
+/// assert_eq!(*pin, 1);
+/// ```
+pub trait WeakPinExt {
+    /// The strong pointer type, `Arc<T>` or `Rc<T>`.
+    type Strong: Deref;
+
+    /// Upgrades the weak pointer, and pins the strong pointer.
+    /// Returns `None` if the value has been dropped.
+    fn upgrade_pin(&self) -> Option<Pin<Self::Strong>>;
+}
+
+/// Used for pinning lists of `OsString`s, like the arguments of a `Command`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::ffi::{OsStr, OsString};
+/// use std::pin::Pin;
+/// use pinpoint::ArgsPinExt;
+///
+/// let args = vec![OsString::from("-v"), OsString::from("file")];
+///
+/// let pinned: Vec<Pin<&OsStr>> = args.pin_args().collect();
+/// assert_eq!(&*pinned[1], "file");
+/// ```
+pub trait ArgsPinExt {
+    /// Returns an iterator over the strings as pinned `OsStr`s.
+    fn pin_args(&self) -> impl Iterator<Item = Pin<&OsStr>>;
+}
+
+/// Used for downcasting type-erased boxes into pinned boxes.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::any::Any;
+/// use std::pin::Pin;
+/// use pinpoint::AnyPinExt;
+///
+/// let erased: Box<dyn Any> = Box::new(1u32);
+///
+/// let pin: Pin<Box<u32>> = erased.downcast_pin().unwrap();
+/// assert_eq!(*pin, 1);
+/// ```
+pub trait AnyPinExt: Sized {
+    /// Downcasts the box to a concrete type, and pins it, like [`Box::downcast`].
+    /// If the types do not match, the original box is returned in the error.
+    ///
+    /// [`Box::downcast`]: https://doc.rust-lang.org/std/boxed/struct.Box.html#method.downcast
+    fn downcast_pin<T: Any>(self) -> Result<Pin<Box<T>>, Self>;
+}
+
+/// Used for pinning the elements at the ends of a `VecDeque`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::collections::VecDeque;
+/// use pinpoint::DequePinExt;
+///
+/// let mut queue: VecDeque<u32> = VecDeque::new();
+/// queue.push_back(1);
+/// queue.push_back(2);
+///
+/// *queue.pin_back_mut().unwrap() += 1;
+/// assert_eq!(*queue.pin_front().unwrap(), 1);
+/// assert_eq!(*queue.pin_back().unwrap(), 3);
+/// ```
+pub trait DequePinExt<T> {
+    /// Returns the first element as a pinned reference, or `None` if the deque is empty.
+    fn pin_front(&self) -> Option<Pin<&T>>;
+
+    /// Returns the first element as a pinned mutable reference, or `None` if the deque is empty.
+    fn pin_front_mut(&mut self) -> Option<Pin<&mut T>>;
+
+    /// Returns the last element as a pinned reference, or `None` if the deque is empty.
+    fn pin_back(&self) -> Option<Pin<&T>>;
+
+    /// Returns the last element as a pinned mutable reference, or `None` if the deque is empty.
+    fn pin_back_mut(&mut self) -> Option<Pin<&mut T>>;
+}
+
+/// Used for reinterpreting pinned byte slices as pinned slices of other types.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::BytesPinExt;
+///
+/// let bytes: &[u8] = &[0; 9];
+///
+/// let (prefix, middle, suffix): (Pin<&[u8]>, Pin<&[u16]>, Pin<&[u8]>) =
+///     unsafe { bytes.pin_align_to() };
+/// assert_eq!(prefix.len() + middle.len() * 2 + suffix.len(), 9);
+/// assert!(middle.iter().all(|&w| w == 0));
+/// ```
+pub trait BytesPinExt<'a> {
+    /// Splits the bytes into a prefix, a middle of correctly aligned `T`s and a suffix, like [`slice::align_to`], and pins each part.
+    /// Bytes that do not fit into an aligned `T` end up in the pinned prefix or suffix.
+    ///
+    /// # Safety
+    /// Every bit pattern of `size_of::<T>()` bytes must be a valid `T`, which is the case for plain old data such as the integer types.
+    /// See [`slice::align_to`] for the full contract.
+    ///
+    /// [`slice::align_to`]: https://doc.rust-lang.org/std/primitive.slice.html#method.align_to
+    #[allow(clippy::type_complexity)]
+    unsafe fn pin_align_to<T: Unpin>(self) -> (Pin<&'a [u8]>, Pin<&'a [T]>, Pin<&'a [u8]>);
+}
+
+/// Used for pinning the values in a key range of ordered maps.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::collections::BTreeMap;
+/// use pinpoint::RangePinExt;
+///
+/// let mut map: BTreeMap<u8, u32> = (0..5).map(|k| (k, 0)).collect();
+///
+/// for mut v in map.pin_range_values_mut(1..3) {
+///     *v += 1;
+/// }
+/// assert_eq!(map.values().sum::<u32>(), 2);
+/// ```
+pub trait RangePinExt<K, V> {
+    /// Returns an iterator over the values with a key in `range` as pinned mutable references, in key order.
+    ///
+    /// # Panics
+    /// Panics if the range is invalid, like [`BTreeMap::range_mut`].
+    ///
+    /// [`BTreeMap::range_mut`]: https://doc.rust-lang.org/std/collections/struct.BTreeMap.html#method.range_mut
+    fn pin_range_values_mut<'a, R: RangeBounds<K>>(
+        &'a mut self,
+        range: R,
+    ) -> impl Iterator<Item = Pin<&'a mut V>>
+    where
+        K: 'a,
+        V: 'a;
+}
+
+/// Used for moving the elements of a boxed slice into pinned boxes of their own.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::BoxedSlicePinExt;
+///
+/// let jobs: Box<[String]> = vec![String::from("a"), String::from("b")].into_boxed_slice();
+///
+/// let pinned: Vec<Pin<Box<String>>> = jobs.into_pin_iter().collect();
+/// assert_eq!(pinned[1].as_str(), "b");
+/// ```
+pub trait BoxedSlicePinExt<T> {
+    /// Returns an iterator that moves each element into its own pinned `Box`.
+    /// Every element that is yielded costs an allocation.
+    fn into_pin_iter(self) -> impl Iterator<Item = Pin<Box<T>>>;
+}
+
+/// Used for converting the value in a pinned `Box` with `Into`.
+/// The converted value is moved into a new allocation.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::PinInto;
+///
+/// let small: Pin<Box<u8>> = Box::pin(7);
+///
+/// let wide: Pin<Box<u32>> = small.pin_into();
+/// assert_eq!(*wide, 7);
+/// ```
+pub trait PinInto<B> {
+    /// Unpins the value, converts it with `Into`, and pins the result in a new `Box`.
+    fn pin_into(self) -> Pin<Box<B>>;
+}
+
+/// Used for leaking a `Box` into a pinned `'static` mutable reference.
+/// The allocation is never freed, so this is meant for values that live for the rest of the program, such as globals that are initialized once.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::LeakPinExt;
+///
+/// let config: Pin<&'static mut Vec<u32>> = Box::new(vec![1, 2]).leak_pin();
+///
+/// assert_eq!(config.len(), 2);
+/// ```
+pub trait LeakPinExt<T: ?Sized> {
+    /// Leaks the `Box`, and pins the reference to its value.
+    /// Since the value is never moved or dropped, this also works for types that are not `Unpin`.
+    fn leak_pin(self) -> Pin<&'static mut T>;
+}
+
+///////////////////////////////////////////////
+// MAP IMPL
+///////////////////////////////////////////////
+impl<K: Eq + Hash, V: Unpin, S: BuildHasher> MapPinExt<K, V> for HashMap<K, V, S> {
+    #[inline]
+    fn into_pin_entries(self) -> Pin<Vec<(K, V)>>
+    where
+        K: Unpin,
+    {
+        Pin::new(self.into_iter().collect())
+    }
+
+    #[inline]
+    fn into_pin_entries_boxed(self) -> Pin<Box<[(K, V)]>>
+    where
+        K: Unpin,
+    {
+        Pin::new(self.into_iter().collect())
+    }
+
+    #[inline]
+    fn pin_values_mut<'a>(&'a mut self) -> impl Iterator<Item = Pin<&'a mut V>>
+    where
+        V: 'a,
+    {
+        self.values_mut().map(Pin::new)
+    }
+}
+
+impl<K: Ord, V: Unpin> MapPinExt<K, V> for BTreeMap<K, V> {
+    #[inline]
+    fn into_pin_entries(self) -> Pin<Vec<(K, V)>>
+    where
+        K: Unpin,
+    {
+        Pin::new(self.into_iter().collect())
+    }
+
+    #[inline]
+    fn into_pin_entries_boxed(self) -> Pin<Box<[(K, V)]>>
+    where
+        K: Unpin,
+    {
+        Pin::new(self.into_iter().collect())
+    }
+
+    #[inline]
+    fn pin_values_mut<'a>(&'a mut self) -> impl Iterator<Item = Pin<&'a mut V>>
+    where
+        V: 'a,
+    {
+        self.values_mut().map(Pin::new)
+    }
+}
+
+impl<K, Q, V, S> MapKeyPinExt<Q, V> for HashMap<K, V, S>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: Eq + Hash + ?Sized,
+    V: Unpin,
+    S: BuildHasher,
+{
+    #[inline]
+    fn pin_value_mut(&mut self, key: &Q) -> Option<Pin<&mut V>> {
+        self.get_mut(key).map(Pin::new)
+    }
+}
+
+impl<K, Q, V> MapKeyPinExt<Q, V> for BTreeMap<K, V>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+    V: Unpin,
+{
+    #[inline]
+    fn pin_value_mut(&mut self, key: &Q) -> Option<Pin<&mut V>> {
+        self.get_mut(key).map(Pin::new)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// SLICE IMPL
+///////////////////////////////////////////////
+impl<'a, T: Unpin> SlicePinExt<'a, T> for &'a [T] {
+    #[inline]
+    fn into_pin_chunks(self, n: usize) -> impl Iterator<Item = Pin<&'a [T]>> {
+        self.chunks(n).map(Pin::new)
+    }
+
+    #[inline]
+    fn into_pin_split_at(self, mid: usize) -> (Pin<&'a [T]>, Pin<&'a [T]>) {
+        let (left, right) = self.split_at(mid);
+        (Pin::new(left), Pin::new(right))
+    }
+
+    #[inline]
+    fn into_pin_windows(self, size: usize) -> impl Iterator<Item = Pin<&'a [T]>> {
+        self.windows(size).map(Pin::new)
+    }
+}
+
+impl<'a, T: Unpin> SliceMutPinExt<'a, T> for &'a mut [T] {
+    #[inline]
+    fn into_pin_split_at_mut(self, mid: usize) -> (Pin<&'a mut [T]>, Pin<&'a mut [T]>) {
+        let (left, right) = self.split_at_mut(mid);
+        (Pin::new(left), Pin::new(right))
+    }
+
+    #[inline]
+    fn into_pin_halves_mut(self) -> (Pin<&'a mut [T]>, Pin<&'a mut [T]>) {
+        let mid = self.len() / 2;
+        self.into_pin_split_at_mut(mid)
+    }
+
+    #[inline]
+    fn pin_iter_mut(self) -> impl Iterator<Item = Pin<&'a mut T>> {
+        self.iter_mut().map(Pin::new)
+    }
+
+    #[inline]
+    fn pin_get_mut(self, i: usize) -> Option<Pin<&'a mut T>> {
+        self.get_mut(i).map(Pin::new)
+    }
+
+    #[inline]
+    fn pin_array_chunks<const N: usize>(self) -> impl Iterator<Item = Pin<&'a mut [T; N]>> {
+        self.as_chunks_mut().0.iter_mut().map(Pin::new)
+    }
+
+    #[inline]
+    fn pin_array_chunks_remainder<const N: usize>(self) -> Pin<&'a mut [T]> {
+        Pin::new(self.as_chunks_mut::<N>().1)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// RESULT IMPL
+///////////////////////////////////////////////
+impl<P: Deref, E> ResultPinExt<P, E> for Result<P, E>
+where
+    P::Target: Unpin,
+{
+    #[inline]
+    fn into_pin_ok(self) -> Result<Pin<P>, E> {
+        self.map(Pin::new)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// PINNED SLICE IMPL
+///////////////////////////////////////////////
+#[cfg(feature = "memchr")]
+impl PinSliceExt for Pin<&[u8]> {
+    #[inline]
+    fn memchr_pin(&self, byte: u8) -> Option<usize> {
+        memchr::memchr(byte, self)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// REFCELL IMPL
+///////////////////////////////////////////////
+impl<T: Unpin + ?Sized> RefCellPinExt<T> for RefCell<T> {
+    #[inline]
+    fn pin_borrow(&self) -> Pin<Ref<'_, T>> {
+        Pin::new(self.borrow())
+    }
+
+    #[inline]
+    fn pin_borrow_mut(&self) -> Pin<RefMut<'_, T>> {
+        Pin::new(self.borrow_mut())
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// ONCE IMPL
+///////////////////////////////////////////////
+impl<T: Unpin> OncePinExt<T> for OnceCell<T> {
+    #[inline]
+    fn pin_get(&self) -> Option<Pin<&T>> {
+        self.get().map(Pin::new)
+    }
+}
+
+impl<T: Unpin> OncePinExt<T> for OnceLock<T> {
+    #[inline]
+    fn pin_get(&self) -> Option<Pin<&T>> {
+        self.get().map(Pin::new)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// DEREF IMPL
+///////////////////////////////////////////////
+impl<D: Deref + ?Sized> DerefPinExt for D
+where
+    D::Target: Unpin,
+{
+    #[inline]
+    fn pin_deref(&self) -> Pin<&Self::Target> {
+        Pin::new(&**self)
+    }
+
+    #[inline]
+    fn pin_deref_mut(&mut self) -> Pin<&mut Self::Target>
+    where
+        Self: DerefMut,
+    {
+        Pin::new(&mut **self)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// ROWS IMPL
+///////////////////////////////////////////////
+impl<'a, T: Unpin> RowsPinExt<'a, T> for &'a [Vec<T>] {
+    #[inline]
+    fn into_pin_rows(self) -> impl Iterator<Item = Pin<&'a [T]>> {
+        self.iter().map(|row| Pin::new(row.as_slice()))
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// PIN MUT IMPL
+///////////////////////////////////////////////
+impl<T: Unpin + ?Sized> PinMutExt<T> for Pin<&mut T> {
+    #[inline]
+    fn pin_as_mut<U: Unpin + ?Sized>(&mut self) -> Pin<&mut U>
+    where
+        T: AsMut<U>,
+    {
+        Pin::new(self.as_mut().get_mut().as_mut())
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// TUPLE IMPL
+///////////////////////////////////////////////
+macro_rules! impl_tuple {
+    ($($P:ident),+) => (
+        impl<$($P: Deref),+> TuplePinExt for ($($P,)+)
+        where
+            $($P::Target: Unpin),+
+        {
+            type Pinned = ($(Pin<$P>,)+);
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn into_pin_tuple(self) -> Self::Pinned {
+                let ($($P,)+) = self;
+                ($(Pin::new($P),)+)
+            }
+        }
+    );
+}
+
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// HEAP IMPL
+///////////////////////////////////////////////
+impl<T: Ord + Unpin> HeapPinExt<T> for BinaryHeap<T> {
+    #[inline]
+    fn pin_into_sorted_vec(self) -> Pin<Vec<T>> {
+        Pin::new(self.into_sorted_vec())
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// FLAT IMPL
+///////////////////////////////////////////////
+impl<'a, T: Unpin, const N: usize> FlatPinExt<'a, T> for &'a [[T; N]] {
+    #[inline]
+    fn into_pin_flat(self) -> Pin<&'a [T]> {
+        Pin::new(self.as_flattened())
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// PATH IMPL
+///////////////////////////////////////////////
+impl PathPinExt for Path {
+    #[inline]
+    fn pin_components(&self) -> impl Iterator<Item = Pin<&OsStr>> {
+        self.components().map(|c| Pin::new(c.as_os_str()))
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// VEC IMPL
+///////////////////////////////////////////////
+impl<T: Unpin> VecPinExt<T> for Vec<T> {
+    #[inline]
+    fn drain_into_pin(&mut self) -> Pin<Box<[T]>> {
+        Pin::new(self.drain(..).collect())
+    }
+
+    #[inline]
+    fn take_pin(&mut self) -> Pin<Vec<T>> {
+        Pin::new(mem::take(self))
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// PINNED MUT SLICE IMPL
+///////////////////////////////////////////////
+impl<'a, T: Unpin> PinSliceMutExt<'a, T> for Pin<&'a mut [T]> {
+    #[inline]
+    fn into_pin_chunks_mut(self, n: usize) -> impl Iterator<Item = Pin<&'a mut [T]>> {
+        Pin::into_inner(self).chunks_mut(n).map(Pin::new)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// MUTEX IMPL
+///////////////////////////////////////////////
+impl<T: Unpin + ?Sized> MutexPinExt<T> for Mutex<T> {
+    #[inline]
+    fn pin_lock(&self) -> LockResult<Pin<MutexGuard<'_, T>>> {
+        match self.lock() {
+            Ok(guard) => Ok(Pin::new(guard)),
+            Err(poisoned) => Err(PoisonError::new(Pin::new(poisoned.into_inner()))),
+        }
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// PAIRS IMPL
+///////////////////////////////////////////////
+impl<'a, K, V: Unpin> PairsPinExt<'a, K, V> for &'a mut [(K, V)] {
+    #[inline]
+    fn pin_values_mut(self) -> impl Iterator<Item = Pin<&'a mut V>> {
+        self.iter_mut().map(|(_, v)| Pin::new(v))
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// ASREF IMPL
+///////////////////////////////////////////////
+impl<S: ?Sized> AsRefPinExt for S {
+    #[inline]
+    fn pin_as_ref<T: Unpin + ?Sized>(&self) -> Pin<&T>
+    where
+        Self: AsRef<T>,
+    {
+        Pin::new(self.as_ref())
+    }
+
+    #[inline]
+    fn as_pin_slice<T: Unpin>(&self) -> Pin<&[T]>
+    where
+        Self: AsRef<[T]>,
+    {
+        Pin::new(self.as_ref())
+    }
+
+    #[inline]
+    fn as_pin_slice_mut<T: Unpin>(&mut self) -> Pin<&mut [T]>
+    where
+        Self: AsMut<[T]>,
+    {
+        Pin::new(self.as_mut())
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// BUFREADER IMPL
+///////////////////////////////////////////////
+impl<R> BufReaderPinExt<R> for BufReader<R> {
+    #[inline]
+    fn into_pin_inner(self) -> Pin<Box<R>> {
+        Box::pin(self.into_inner())
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// WEAK IMPL
+///////////////////////////////////////////////
+impl<T: Unpin + ?Sized> WeakPinExt for sync::Weak<T> {
+    type Strong = Arc<T>;
+
+    #[inline]
+    fn upgrade_pin(&self) -> Option<Pin<Arc<T>>> {
+        self.upgrade().map(Pin::new)
+    }
+}
+
+impl<T: Unpin + ?Sized> WeakPinExt for rc::Weak<T> {
+    type Strong = Rc<T>;
+
+    #[inline]
+    fn upgrade_pin(&self) -> Option<Pin<Rc<T>>> {
+        self.upgrade().map(Pin::new)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// ARGS IMPL
+///////////////////////////////////////////////
+impl ArgsPinExt for [OsString] {
+    #[inline]
+    fn pin_args(&self) -> impl Iterator<Item = Pin<&OsStr>> {
+        self.iter().map(|arg| Pin::new(arg.as_os_str()))
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// ANY IMPL
+///////////////////////////////////////////////
+impl AnyPinExt for Box<dyn Any> {
+    #[inline]
+    fn downcast_pin<T: Any>(self) -> Result<Pin<Box<T>>, Self> {
+        self.downcast().map(Box::into_pin)
+    }
+}
+
+impl AnyPinExt for Box<dyn Any + Send> {
+    #[inline]
+    fn downcast_pin<T: Any>(self) -> Result<Pin<Box<T>>, Self> {
+        self.downcast().map(Box::into_pin)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// DEQUE IMPL
+///////////////////////////////////////////////
+impl<T: Unpin> DequePinExt<T> for VecDeque<T> {
+    #[inline]
+    fn pin_front(&self) -> Option<Pin<&T>> {
+        self.front().map(Pin::new)
+    }
+
+    #[inline]
+    fn pin_front_mut(&mut self) -> Option<Pin<&mut T>> {
+        self.front_mut().map(Pin::new)
+    }
+
+    #[inline]
+    fn pin_back(&self) -> Option<Pin<&T>> {
+        self.back().map(Pin::new)
+    }
+
+    #[inline]
+    fn pin_back_mut(&mut self) -> Option<Pin<&mut T>> {
+        self.back_mut().map(Pin::new)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// BYTES IMPL
+///////////////////////////////////////////////
+impl<'a> BytesPinExt<'a> for &'a [u8] {
+    #[inline]
+    unsafe fn pin_align_to<T: Unpin>(self) -> (Pin<&'a [u8]>, Pin<&'a [T]>, Pin<&'a [u8]>) {
+        let (prefix, middle, suffix) = self.align_to();
+        (Pin::new(prefix), Pin::new(middle), Pin::new(suffix))
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// RANGE IMPL
+///////////////////////////////////////////////
+impl<K: Ord, V: Unpin> RangePinExt<K, V> for BTreeMap<K, V> {
+    #[inline]
+    fn pin_range_values_mut<'a, R: RangeBounds<K>>(
+        &'a mut self,
+        range: R,
+    ) -> impl Iterator<Item = Pin<&'a mut V>>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.range_mut(range).map(|(_, v)| Pin::new(v))
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// BOXED SLICE IMPL
+///////////////////////////////////////////////
+impl<T> BoxedSlicePinExt<T> for Box<[T]> {
+    #[inline]
+    fn into_pin_iter(self) -> impl Iterator<Item = Pin<Box<T>>> {
+        self.into_vec().into_iter().map(Box::pin)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// INTO IMPL
+///////////////////////////////////////////////
+impl<A: Unpin + Into<B>, B> PinInto<B> for Pin<Box<A>> {
+    #[inline]
+    fn pin_into(self) -> Pin<Box<B>> {
+        Box::pin((*Pin::into_inner(self)).into())
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// LEAK IMPL
+///////////////////////////////////////////////
+impl<T: ?Sized + 'static> LeakPinExt<T> for Box<T> {
+    #[inline]
+    fn leak_pin(self) -> Pin<&'static mut T> {
+        Pin::static_mut(Box::leak(self))
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
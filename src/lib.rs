@@ -1,4 +1,3 @@
-#![cfg_attr(feature = "slice_of_cells", feature(as_cell))]
 //! This crate provides the `IntoPin` trait.
 //! `IntoPin` can be used to wrap any type in a [`Pin`],
 //! but is powerfull in creating  coerced, pinned references.
@@ -40,13 +39,298 @@
 //! example(&mut b);
 //! assert_eq!(*b, [1, 2, 3, 4]);
 //! ```
+//!
+//! Types implementing `Deref`, such as newtypes, can be pinned to their target with `DerefPinExt`:
+//! ```
+//!
+//! extern crate pinpoint;
+//! use std::ops::Deref;
+//! use std::pin::Pin;
+//! use pinpoint::DerefPinExt;
+//!
+//! struct Wrapper(Vec<u8>);
+//!
+//! impl Deref for Wrapper {
+//!     type Target = [u8];
+//!
+//!     fn deref(&self) -> &[u8] {
+//!         &self.0
+//!     }
+//! }
+//!
+//! let w = Wrapper(vec![1, 2, 3]);
+//!
+//! let pin: Pin<&[u8]> = w.pin_deref();
+//! assert_eq!(*pin, [1, 2, 3]);
+//! ```
+//! # Unpin and pinning
+//!
+//! Pinning a value that is `Unpin` is a no-op: the value can still be moved out of the pin,
+//! so a reference to it can be pinned in place, wherever it lives. This is what `IntoPin` is built on.
+//! A value that is not `Unpin` has to be moved to the heap instead, before it can be pinned safely.
+//! Generic code can not pick between the two with a single call, so there are two entry points:
+//! [`pin_stack_or_heap`] pins a `&mut T` in place for `T: Unpin`, and [`pin_heap`] pins any `T` in a `Box`.
+//!
+//! [`pin_stack_or_heap`]: fn.pin_stack_or_heap.html
+//! [`pin_heap`]: fn.pin_heap.html
 //! # Features
 //!
 //! In order to use the `IntoPin` trait, this crate should be used with the feature `pinned` of this crate turned on.
 //! In order to create a pinned slice containg Cell types from a Cell containing a slice, use the `slice_of_cells` feature of this crate.
+//! The `arrays` feature is enabled by default and provides the impls for references to arrays, such as `&[T; N]` to `Pin<&[T]>`,
+//! and for arrays by value, such as `[T; N]` to `Pin<Box<[T]>>` and `Pin<Vec<T>>`.
+//! Disabling it with `default-features = false` removes those impls, but keeps the rest of the crate available.
+//! On Unix, the `unix_bytes` feature of this crate allows pinning paths and OS strings as their raw bytes.
+//! In order to search pinned byte slices with `PinSliceExt`, use the `memchr` feature of this crate.
 
+#[macro_use]
+mod macros;
+pub mod builder;
+pub mod ext;
 pub mod pinned;
 
-pub use self::pinned::IntoPin;
+pub use self::builder::PinBuilder;
+#[cfg(feature = "memchr")]
+pub use self::ext::PinSliceExt;
+pub use self::ext::{
+    AnyPinExt, ArgsPinExt, AsRefPinExt, BoxedSlicePinExt, BufReaderPinExt, BytesPinExt,
+    DequePinExt, DerefPinExt, FlatPinExt, HeapPinExt, LeakPinExt, MapKeyPinExt, MapPinExt,
+    MutexPinExt, OncePinExt, PairsPinExt, PathPinExt, PinInto, PinMutExt, PinSliceMutExt,
+    RangePinExt, RefCellPinExt, ResultPinExt, RowsPinExt, SliceMutPinExt, SlicePinExt, TuplePinExt,
+    VecPinExt, WeakPinExt,
+};
+pub use self::pinned::{IntoPin, TryIntoPin};
+
+use std::mem;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Pins `src` as a `Pin<T>`.
+/// This is [`IntoPin::into_pin`] as a free function, so the target type can be given with a turbofish.
+///
+/// [`IntoPin::into_pin`]: trait.IntoPin.html#tymethod.into_pin
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+///
+/// let v = vec![1, 2, 3, 4];
+///
+/// assert_eq!(pinpoint::pin::<&[u32], _>(&v).len(), 4);
+/// ```
+/// The target type can also be inferred from the surrounding context:
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+///
+/// fn sum(pin: Pin<&[u32]>) -> u32 {
+///     pin.iter().sum()
+/// }
+///
+/// let v = vec![1, 2, 3, 4];
+///
+/// assert_eq!(sum(pinpoint::pin(&v)), 10);
+/// ```
+#[inline]
+pub fn pin<T: Unpin, S: IntoPin<T>>(src: S) -> Pin<T> {
+    src.into_pin()
+}
+
+/// Clones the data behind a pinned reference into a pinned owned value.
+/// The result no longer borrows from `p`, so it can outlive it.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::IntoPin;
+///
+/// let pin: Pin<String> = {
+///     let s = String::from("hello");
+///     let borrowed: Pin<&str> = (&s).into_pin();
+///     pinpoint::into_owned_pin(borrowed)
+/// };
+///
+/// assert_eq!(&*pin, "hello");
+/// ```
+#[inline]
+pub fn into_owned_pin<T: ToOwned + ?Sized>(p: Pin<&T>) -> Pin<T::Owned>
+where
+    T::Owned: Deref,
+    <T::Owned as Deref>::Target: Unpin,
+{
+    Pin::new(Pin::get_ref(p).to_owned())
+}
+
+/// Collects an iterator into a pinned `Vec`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+///
+/// let pin: Pin<Vec<i32>> = pinpoint::collect_pin(0..5);
+///
+/// assert_eq!(pin[3], 3);
+/// ```
+#[inline]
+pub fn collect_pin<T: Unpin, I: IntoIterator<Item = T>>(iter: I) -> Pin<Vec<T>> {
+    Pin::new(iter.into_iter().collect())
+}
+
+/// Pins the pointer inside an `Option`, mapping `None` to `None`.
+/// This works for any pointer whose target is `Unpin`, such as `Option<&T>` and `Option<&mut T>`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+///
+/// let mut values = vec![1, 2, 3];
+///
+/// let pins: Vec<Option<Pin<&mut u32>>> = values
+///     .iter_mut()
+///     .map(|v| if *v % 2 == 1 { Some(v) } else { None })
+///     .map(pinpoint::transpose_option_pin)
+///     .collect();
+///
+/// assert_eq!(pins.iter().filter(|p| p.is_some()).count(), 2);
+/// ```
+#[inline]
+pub fn transpose_option_pin<P: Deref>(opt: Option<P>) -> Option<Pin<P>>
+where
+    P::Target: Unpin,
+{
+    opt.map(Pin::new)
+}
+
+/// Replaces `dest` with `src`, and returns the old value pinned in a `Box`, like [`mem::replace`].
+///
+/// [`mem::replace`]: https://doc.rust-lang.org/std/mem/fn.replace.html
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+///
+/// let mut state = String::from("old");
+///
+/// let old: Pin<Box<String>> = pinpoint::pin_replace(&mut state, String::from("new"));
+/// assert_eq!(old.as_str(), "old");
+/// assert_eq!(state, "new");
+/// ```
+#[inline]
+pub fn pin_replace<T>(dest: &mut T, src: T) -> Pin<Box<T>> {
+    Box::pin(mem::replace(dest, src))
+}
+
+/// Zips two mutable slices into pairs of pinned mutable references to their elements.
+/// If the slices differ in length, the iterator stops at the end of the shorter one.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+///
+/// let mut xs = [1, 2, 3];
+/// let mut ys = [10, 20, 30];
+///
+/// for (mut x, mut y) in pinpoint::pin_zip_mut(&mut xs, &mut ys) {
+///     *y += *x;
+///     *x = 0;
+/// }
+/// assert_eq!(xs, [0, 0, 0]);
+/// assert_eq!(ys, [11, 22, 33]);
+/// ```
+#[inline]
+pub fn pin_zip_mut<'a, A: Unpin, B: Unpin>(
+    a: &'a mut [A],
+    b: &'a mut [B],
+) -> impl Iterator<Item = (Pin<&'a mut A>, Pin<&'a mut B>)> {
+    a.iter_mut()
+        .zip(b.iter_mut())
+        .map(|(a, b)| (Pin::new(a), Pin::new(b)))
+}
+
+/// Pins `value` in place, wherever it lives. This does not move or allocate, and is only possible for `T: Unpin`.
+/// For a `T` that is not `Unpin`, use [`pin_heap`] instead.
+///
+/// [`pin_heap`]: fn.pin_heap.html
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+///
+/// let mut count = 0u32;
+///
+/// let mut pin: Pin<&mut u32> = pinpoint::pin_stack_or_heap(&mut count);
+/// *pin += 1;
+/// assert_eq!(count, 1);
+/// ```
+#[inline]
+pub fn pin_stack_or_heap<T: Unpin + ?Sized>(value: &mut T) -> Pin<&mut T> {
+    Pin::new(value)
+}
+
+/// Pins `value` by moving it into a new `Box`. This works for any `T`, including types that are not `Unpin`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::marker::PhantomPinned;
+/// use std::pin::Pin;
+///
+/// let pin: Pin<Box<(u32, PhantomPinned)>> = pinpoint::pin_heap((1, PhantomPinned));
+/// assert_eq!(pin.0, 1);
+/// ```
+#[inline]
+pub fn pin_heap<T>(value: T) -> Pin<Box<T>> {
+    Box::pin(value)
+}
+
+/// Moves the value out of a `Box` into a pinned `Arc`.
+/// This allocates, because an `Arc` keeps its reference counts in the same allocation as the value.
+/// It is not an `IntoPin` impl, since `Box<T>` already converts into `Pin<Arc<Box<T>>>`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use std::sync::Arc;
+///
+/// let boxed: Box<str> = "shared".into();
+///
+/// let pin: Pin<Arc<str>> = pinpoint::box_into_pin_arc(boxed);
+/// assert_eq!(&*pin, "shared");
+/// ```
+#[inline]
+pub fn box_into_pin_arc<T: Unpin + ?Sized>(boxed: Box<T>) -> Pin<Arc<T>> {
+    Pin::new(Arc::from(boxed))
+}
+
+/// Moves the value out of a `Box` into a pinned `Rc`.
+/// Like [`box_into_pin_arc`], this allocates room for the reference counts next to the value.
+///
+/// [`box_into_pin_arc`]: fn.box_into_pin_arc.html
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use std::rc::Rc;
+///
+/// let boxed: Box<[u8]> = vec![1, 2, 3].into_boxed_slice();
+///
+/// let pin: Pin<Rc<[u8]>> = pinpoint::box_into_pin_rc(boxed);
+/// assert_eq!(pin.len(), 3);
+/// ```
+#[inline]
+pub fn box_into_pin_rc<T: Unpin + ?Sized>(boxed: Box<T>) -> Pin<Rc<T>> {
+    Pin::new(Rc::from(boxed))
+}
 
+#[cfg(test)]
 mod tests;
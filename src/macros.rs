@@ -0,0 +1,155 @@
+//! Macros exported by this crate.
+
+/// Implements the `IntoPin` reference coercions for a custom pointer type.
+/// For a type `P<T>` that implements `Deref`, this implements `IntoPin` from `&P<T>` and `&mut P<T>` into a pinned reference to the target of `P<T>`.
+/// The coercion from `&mut P<T>` into a pinned mutable reference only applies if `P<T>` also implements `DerefMut`.
+/// # Examples
+/// ```
+///
+/// #[macro_use]
+/// extern crate pinpoint;
+/// use std::ops::{Deref, DerefMut};
+/// use std::pin::Pin;
+/// use pinpoint::IntoPin;
+///
+/// struct MyPtr<T>(Box<T>);
+///
+/// impl<T> Deref for MyPtr<T> {
+///     type Target = T;
+///
+///     fn deref(&self) -> &T {
+///         &self.0
+///     }
+/// }
+///
+/// impl<T> DerefMut for MyPtr<T> {
+///     fn deref_mut(&mut self) -> &mut T {
+///         &mut self.0
+///     }
+/// }
+///
+/// impl_into_pin!(MyPtr<T>);
+///
+/// # fn main() {
+/// let mut p = MyPtr(Box::new(1));
+///
+/// let mut pin: Pin<&mut u32> = (&mut p).into_pin();
+/// *pin += 1;
+///
+/// let pin: Pin<&u32> = (&p).into_pin();
+/// assert_eq!(*pin, 2);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! impl_into_pin {
+    ($ptr:ident < $($param:ident),+ >) => {
+        impl<'a, $($param),+> $crate::IntoPin<&'a <$ptr<$($param),+> as ::std::ops::Deref>::Target>
+            for &'a $ptr<$($param),+>
+        where
+            $ptr<$($param),+>: ::std::ops::Deref,
+            <$ptr<$($param),+> as ::std::ops::Deref>::Target: ::std::marker::Unpin,
+        {
+            #[inline]
+            fn into_pin(
+                self,
+            ) -> ::std::pin::Pin<&'a <$ptr<$($param),+> as ::std::ops::Deref>::Target> {
+                ::std::pin::Pin::new(&**self)
+            }
+        }
+
+        impl<'a, $($param),+> $crate::IntoPin<&'a <$ptr<$($param),+> as ::std::ops::Deref>::Target>
+            for &'a mut $ptr<$($param),+>
+        where
+            $ptr<$($param),+>: ::std::ops::Deref,
+            <$ptr<$($param),+> as ::std::ops::Deref>::Target: ::std::marker::Unpin,
+        {
+            #[inline]
+            fn into_pin(
+                self,
+            ) -> ::std::pin::Pin<&'a <$ptr<$($param),+> as ::std::ops::Deref>::Target> {
+                ::std::pin::Pin::new(&**self)
+            }
+        }
+
+        impl<'a, $($param),+> $crate::IntoPin<&'a mut <$ptr<$($param),+> as ::std::ops::Deref>::Target>
+            for &'a mut $ptr<$($param),+>
+        where
+            $ptr<$($param),+>: ::std::ops::DerefMut,
+            <$ptr<$($param),+> as ::std::ops::Deref>::Target: ::std::marker::Unpin,
+        {
+            #[inline]
+            fn into_pin(
+                self,
+            ) -> ::std::pin::Pin<&'a mut <$ptr<$($param),+> as ::std::ops::Deref>::Target> {
+                ::std::pin::Pin::new(&mut **self)
+            }
+        }
+    };
+}
+
+/// Pins a reference to a single field of a tuple or struct, as `Pin<&F>`.
+/// `pin_field!(value, 1)` expands to `Pin::new(&value.1)`, so the field is required to be `Unpin`.
+/// The value may be given by reference, such as `&(A, B, C)`.
+/// # Examples
+/// ```
+///
+/// #[macro_use]
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+///
+/// # fn main() {
+/// let tuple = (1u8, String::from("two"), 3u32);
+/// let t = &tuple;
+///
+/// let pin: Pin<&String> = pin_field!(t, 1);
+/// assert_eq!(&**pin, "two");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! pin_field {
+    ($value:expr, $field:tt) => {
+        ::std::pin::Pin::new(&($value).$field)
+    };
+}
+
+/// Projects a `Pin<&mut S>` to pinned mutable references to fields of `S`.
+/// `pin_project_fields!(pin, field)` produces a `Pin<&mut F>` for that field,
+/// and `pin_project_fields!(pin, a, b)` produces a tuple with a pin for each of the fields.
+///
+/// This does not use any unsafe code: `S` is required to be `Unpin`, which is the case if all of its fields are.
+/// The pin is reborrowed, so it can still be used once the projections are dropped.
+/// # Examples
+/// ```
+///
+/// #[macro_use]
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+///
+/// struct State {
+///     name: String,
+///     hits: u32,
+/// }
+///
+/// # fn main() {
+/// let mut state = State { name: String::from("a"), hits: 0 };
+/// let mut pin = Pin::new(&mut state);
+///
+/// let (mut name, mut hits) = pin_project_fields!(pin, name, hits);
+/// name.push('b');
+/// *hits += 1;
+///
+/// *pin_project_fields!(pin, hits) += 1;
+/// assert_eq!(state.name, "ab");
+/// assert_eq!(state.hits, 2);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! pin_project_fields {
+    ($pin:expr, $field:tt) => {
+        ::std::pin::Pin::new(&mut ::std::pin::Pin::get_mut(::std::pin::Pin::as_mut(&mut $pin)).$field)
+    };
+    ($pin:expr, $($field:tt),+) => {{
+        let projected = ::std::pin::Pin::get_mut(::std::pin::Pin::as_mut(&mut $pin));
+        ($(::std::pin::Pin::new(&mut projected.$field)),+)
+    }};
+}
@@ -1,10 +1,14 @@
 use std::borrow::Cow;
 use std::cell::{Cell, Ref, RefMut};
-use std::ffi::{OsStr, OsString};
+use std::ffi::{CStr, CString, NulError, OsStr, OsString};
+use std::io::{IoSlice, IoSliceMut};
 use std::marker::Unpin;
+#[cfg(all(unix, feature = "unix_bytes"))]
+use std::os::unix::ffi::{OsStrExt, OsStringExt};
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::rc::Rc;
+use std::str::{self, Utf8Error};
 use std::sync::Arc;
 
 /// Used for pinning pointer/reference types.
@@ -45,11 +49,73 @@ use std::sync::Arc;
 /// example(&mut b);
 /// assert_eq!(*b, [1, 2, 3, 4]);
 /// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can not be pinned as `Pin<{T}>` by `IntoPin`",
+    label = "`IntoPin<{T}>` is not implemented for `{Self}`",
+    note = "the implementors of `IntoPin` list the conversions it supports; a value that is not `Unpin` can be pinned with `Box::pin` or the `pin!` macro instead"
+)]
 pub trait IntoPin<T: Unpin> {
     /// Performs the wrapping.
     fn into_pin(self) -> Pin<T>;
 }
 
+/// Used for fallibly pinning pointer/reference types.
+/// This is the fallible counterpart of `IntoPin`, for coercions that need to validate their input first, for example `&[u8]` to `Pin<&str>`.
+/// # Examples
+/// ```
+///
+/// extern crate pinpoint;
+/// use std::pin::Pin;
+/// use pinpoint::TryIntoPin;
+///
+/// let bytes: &[u8] = b"hello";
+///
+/// let pin: Pin<&str> = bytes.try_into_pin().unwrap();
+/// assert_eq!(&*pin, "hello");
+///
+/// let invalid: &[u8] = &[0xff, 0xfe];
+/// assert!(TryIntoPin::<&str>::try_into_pin(invalid).is_err());
+/// ```
+pub trait TryIntoPin<T: Unpin> {
+    /// The type returned when the conversion fails.
+    type Error;
+
+    /// Performs the wrapping, or returns an error if `self` can not be converted.
+    fn try_into_pin(self) -> Result<Pin<T>, Self::Error>;
+}
+
+// FALLIBLE IMPL
+
+// Does not allocate, the pinned str points into the original bytes.
+impl<'a> TryIntoPin<&'a str> for &'a [u8] {
+    type Error = Utf8Error;
+
+    #[inline]
+    fn try_into_pin(self) -> Result<Pin<&'a str>, Utf8Error> {
+        str::from_utf8(self).map(Pin::new)
+    }
+}
+
+// The nul terminator is appended, an interior nul byte is an error.
+impl TryIntoPin<CString> for Vec<u8> {
+    type Error = NulError;
+
+    #[inline]
+    fn try_into_pin(self) -> Result<Pin<CString>, NulError> {
+        CString::new(self).map(Pin::new)
+    }
+}
+
+// The original OsString is returned if it is not valid Unicode.
+impl TryIntoPin<String> for OsString {
+    type Error = OsString;
+
+    #[inline]
+    fn try_into_pin(self) -> Result<Pin<String>, OsString> {
+        self.into_string().map(Pin::new)
+    }
+}
+
 ///////////////////////////////////////////////
 // Pin<T> IMPL
 //
@@ -71,7 +137,7 @@ impl<'a, T: Unpin + ?Sized> IntoPin<&'a T> for Pin<&'a mut T> {
     }
 }
 
-impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Pin<&'long T> {
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Pin<&T> {
     #[inline]
     fn into_pin(self) -> Pin<&'short T> {
         Pin::new(self)
@@ -79,7 +145,7 @@ impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Pin<&'long
 }
 
 // Mutable reference to pin of reference into pin of reference
-impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short mut Pin<&'long T> {
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short T> for &'short mut Pin<&T> {
     #[inline]
     fn into_pin(self) -> Pin<&'short T> {
         Pin::new(&*self)
@@ -87,7 +153,7 @@ impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short mut Pin<&'
 }
 
 // mutable reference to pin of mutable reference into pin of mutable reference
-impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short mut T> for &'short mut Pin<&'long mut T> {
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short mut T> for &'short mut Pin<&mut T> {
     #[inline]
     fn into_pin(self) -> Pin<&'short mut T> {
         Pin::new(&mut *self)
@@ -95,7 +161,7 @@ impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short mut T> for &'short mut Pi
 }
 
 // mutable reference to pin of mutable reference into pin of reference
-impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short mut Pin<&'long mut T> {
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short T> for &'short mut Pin<&mut T> {
     #[inline]
     fn into_pin(self) -> Pin<&'short T> {
         Pin::new(&*self)
@@ -103,7 +169,7 @@ impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short mut Pin<&'
 }
 
 // reference to pin of mutable reference into pin of reference
-impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Pin<&'long mut T> {
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Pin<&mut T> {
     #[inline]
     fn into_pin(self) -> Pin<&'short T> {
         Pin::new(self)
@@ -115,6 +181,7 @@ impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Pin<&'long
 ///////////////////////////////////////////////
 // GENERIC IMPL
 ///////////////////////////////////////////////
+#[diagnostic::do_not_recommend]
 impl<'a, T: Unpin + ?Sized> IntoPin<&'a T> for &'a T {
     #[inline]
     fn into_pin(self) -> Pin<&'a T> {
@@ -122,6 +189,7 @@ impl<'a, T: Unpin + ?Sized> IntoPin<&'a T> for &'a T {
     }
 }
 
+#[diagnostic::do_not_recommend]
 impl<'a, T: Unpin + ?Sized> IntoPin<&'a T> for &'a mut T {
     #[inline]
     fn into_pin(self) -> Pin<&'a T> {
@@ -129,6 +197,7 @@ impl<'a, T: Unpin + ?Sized> IntoPin<&'a T> for &'a mut T {
     }
 }
 
+#[diagnostic::do_not_recommend]
 impl<'a, T: Unpin + ?Sized> IntoPin<&'a mut T> for &'a mut T {
     #[inline]
     fn into_pin(self) -> Pin<&'a mut T> {
@@ -181,6 +250,20 @@ impl<T: Unpin> IntoPin<Box<[T]>> for Vec<T> {
     }
 }
 
+impl<T: Unpin> IntoPin<Arc<[T]>> for Vec<T> {
+    #[inline]
+    fn into_pin(self) -> Pin<Arc<[T]>> {
+        Pin::new(Arc::from(self))
+    }
+}
+
+impl<T: Unpin> IntoPin<Rc<[T]>> for Vec<T> {
+    #[inline]
+    fn into_pin(self) -> Pin<Rc<[T]>> {
+        Pin::new(Rc::from(self))
+    }
+}
+
 impl<'a, T: Unpin> IntoPin<&'a [T]> for &'a Vec<T> {
     #[inline]
     fn into_pin(self) -> Pin<&'a [T]> {
@@ -201,6 +284,20 @@ impl<'a, T: Unpin> IntoPin<&'a mut [T]> for &'a mut Vec<T> {
         Pin::new(self)
     }
 }
+
+impl<'a, T: Unpin> IntoPin<&'a [T]> for &'a Pin<Vec<T>> {
+    #[inline]
+    fn into_pin(self) -> Pin<&'a [T]> {
+        self.as_ref()
+    }
+}
+
+impl<'a, T: Unpin> IntoPin<&'a mut [T]> for &'a mut Pin<Vec<T>> {
+    #[inline]
+    fn into_pin(self) -> Pin<&'a mut [T]> {
+        self.as_mut()
+    }
+}
 ///////////////////////////////////////////////
 ///////////////////////////////////////////////
 
@@ -255,12 +352,28 @@ impl<'a> IntoPin<&'a [u8]> for &'a String {
         Pin::new(self.as_ref())
     }
 }
+
+impl<'a> IntoPin<&'a str> for &'a Pin<String> {
+    #[inline]
+    fn into_pin(self) -> Pin<&'a str> {
+        self.as_ref()
+    }
+}
+
+impl<'a> IntoPin<&'a mut str> for &'a mut Pin<String> {
+    #[inline]
+    fn into_pin(self) -> Pin<&'a mut str> {
+        self.as_mut()
+    }
+}
 ///////////////////////////////////////////////
 ///////////////////////////////////////////////
 
 ///////////////////////////////////////////////
 // STR IMPL
 ///////////////////////////////////////////////
+// There is no safe way to go from `&mut str` to `&mut OsStr` or `&mut Path`,
+// so the `&mut str` impls reborrow as `&str` and only hand out shared pins.
 impl<'a> IntoPin<&'a [u8]> for &'a str {
     fn into_pin(self) -> Pin<&'a [u8]> {
         Pin::new(self.as_bytes())
@@ -269,7 +382,8 @@ impl<'a> IntoPin<&'a [u8]> for &'a str {
 
 impl<'a> IntoPin<&'a [u8]> for &'a mut str {
     fn into_pin(self) -> Pin<&'a [u8]> {
-        Pin::new(self.as_bytes())
+        let this: &'a str = self;
+        Pin::new(this.as_bytes())
     }
 }
 
@@ -281,7 +395,8 @@ impl<'a> IntoPin<&'a OsStr> for &'a str {
 
 impl<'a> IntoPin<&'a OsStr> for &'a mut str {
     fn into_pin(self) -> Pin<&'a OsStr> {
-        Pin::new((&*self).as_ref())
+        let this: &'a str = self;
+        Pin::new(this.as_ref())
     }
 }
 
@@ -293,7 +408,24 @@ impl<'a> IntoPin<&'a Path> for &'a str {
 
 impl<'a> IntoPin<&'a Path> for &'a mut str {
     fn into_pin(self) -> Pin<&'a Path> {
-        Pin::new((&*self).as_ref())
+        let this: &'a str = self;
+        Pin::new(this.as_ref())
+    }
+}
+
+// Allocates, the string is copied into a new allocation.
+impl IntoPin<Box<str>> for &str {
+    #[inline]
+    fn into_pin(self) -> Pin<Box<str>> {
+        Pin::new(Box::from(self))
+    }
+}
+
+// Allocates, the string is copied into a new allocation.
+impl IntoPin<String> for &str {
+    #[inline]
+    fn into_pin(self) -> Pin<String> {
+        Pin::new(self.to_owned())
     }
 }
 ///////////////////////////////////////////////
@@ -350,6 +482,14 @@ impl<'a> IntoPin<&'a OsStr> for &'a mut PathBuf {
         Pin::new(self.as_os_str())
     }
 }
+
+#[cfg(all(unix, feature = "unix_bytes"))]
+impl IntoPin<Vec<u8>> for PathBuf {
+    #[inline]
+    fn into_pin(self) -> Pin<Vec<u8>> {
+        Pin::new(self.into_os_string().into_vec())
+    }
+}
 ///////////////////////////////////////////////
 ///////////////////////////////////////////////
 
@@ -369,6 +509,14 @@ impl<'a> IntoPin<&'a OsStr> for &'a mut Path {
         Pin::new(self.as_os_str())
     }
 }
+
+#[cfg(all(unix, feature = "unix_bytes"))]
+impl<'a> IntoPin<&'a [u8]> for &'a Path {
+    #[inline]
+    fn into_pin(self) -> Pin<&'a [u8]> {
+        Pin::new(self.as_os_str().as_bytes())
+    }
+}
 ///////////////////////////////////////////////
 ///////////////////////////////////////////////
 
@@ -397,6 +545,14 @@ impl IntoPin<PathBuf> for OsString {
     }
 }
 
+#[cfg(all(unix, feature = "unix_bytes"))]
+impl IntoPin<Vec<u8>> for OsString {
+    #[inline]
+    fn into_pin(self) -> Pin<Vec<u8>> {
+        Pin::new(self.into_vec())
+    }
+}
+
 impl<'a> IntoPin<&'a OsStr> for &'a OsString {
     #[inline]
     fn into_pin(self) -> Pin<&'a OsStr> {
@@ -421,7 +577,7 @@ impl<'a> IntoPin<&'a Path> for &'a OsString {
 impl<'a> IntoPin<&'a Path> for &'a mut OsString {
     #[inline]
     fn into_pin(self) -> Pin<&'a Path> {
-        Pin::new((&*self).as_ref())
+        Pin::new((*self).as_ref())
     }
 }
 ///////////////////////////////////////////////
@@ -436,6 +592,14 @@ impl<'a> IntoPin<&'a Path> for &'a OsStr {
         Pin::new(self.as_ref())
     }
 }
+
+#[cfg(all(unix, feature = "unix_bytes"))]
+impl<'a> IntoPin<&'a [u8]> for &'a OsStr {
+    #[inline]
+    fn into_pin(self) -> Pin<&'a [u8]> {
+        Pin::new(self.as_bytes())
+    }
+}
 ///////////////////////////////////////////////
 ///////////////////////////////////////////////
 
@@ -470,6 +634,20 @@ impl<'a, T: Unpin + ?Sized> IntoPin<&'a mut T> for &'a mut Box<T> {
     }
 }
 
+impl<'a, T: Unpin + ?Sized> IntoPin<&'a T> for &'a Box<Box<T>> {
+    #[inline]
+    fn into_pin(self) -> Pin<&'a T> {
+        Pin::new(&**self)
+    }
+}
+
+impl<'a, T: Unpin + ?Sized> IntoPin<&'a mut T> for &'a mut Box<Box<T>> {
+    #[inline]
+    fn into_pin(self) -> Pin<&'a mut T> {
+        Pin::new(&mut **self)
+    }
+}
+
 impl IntoPin<Box<[u8]>> for Box<str> {
     #[inline]
     fn into_pin(self) -> Pin<Box<[u8]>> {
@@ -477,6 +655,13 @@ impl IntoPin<Box<[u8]>> for Box<str> {
     }
 }
 
+impl IntoPin<String> for Box<str> {
+    #[inline]
+    fn into_pin(self) -> Pin<String> {
+        Pin::new(str::into_string(self))
+    }
+}
+
 impl<T: Unpin> IntoPin<Vec<T>> for Box<[T]> {
     #[inline]
     fn into_pin(self) -> Pin<Vec<T>> {
@@ -504,27 +689,50 @@ impl IntoPin<PathBuf> for Box<Path> {
 ///////////////////////////////////////////////
 // COW IMPL
 ///////////////////////////////////////////////
-impl<'a, T: Clone + Unpin + ?Sized> IntoPin<Cow<'a, T>> for Cow<'a, T> {
+impl<'a, B: ToOwned + Unpin + ?Sized> IntoPin<Cow<'a, B>> for Cow<'a, B>
+where
+    B::Owned: Unpin,
+{
     #[inline]
     fn into_pin(self) -> Pin<Self> {
         Pin::new(self)
     }
 }
 
-impl<'short, 'long, T: Clone + Unpin + ?Sized> IntoPin<&'short T> for &'short Cow<'long, T> {
+impl<'short, 'long, B: ToOwned + Unpin + ?Sized> IntoPin<&'short B> for &'short Cow<'long, B> {
     #[inline]
-    fn into_pin(self) -> Pin<&'short T> {
+    fn into_pin(self) -> Pin<&'short B> {
         Pin::new(self.as_ref())
     }
 }
 
-impl<'short, 'long, T: Clone + Unpin + ?Sized> IntoPin<&'short T> for &'short mut Cow<'long, T> {
+impl<'short, 'long, B: ToOwned + Unpin + ?Sized> IntoPin<&'short B> for &'short mut Cow<'long, B> {
     #[inline]
-    fn into_pin(self) -> Pin<&'short T> {
+    fn into_pin(self) -> Pin<&'short B> {
         Pin::new(&*self)
     }
 }
 
+// May allocate, a Cow::Borrowed gets cloned into a Cow::Owned first.
+// Only for `T: Clone`, since `to_mut` hands out a `&mut B::Owned` rather than a `&mut B`.
+impl<'short, 'long, T: Clone + Unpin> IntoPin<&'short mut T> for &'short mut Cow<'long, T> {
+    #[inline]
+    fn into_pin(self) -> Pin<&'short mut T> {
+        Pin::new(self.to_mut())
+    }
+}
+
+// Does not allocate, the result is always Cow::Borrowed.
+impl<'a, B: ToOwned + Unpin + ?Sized> IntoPin<Cow<'a, B>> for &'a B
+where
+    B::Owned: Unpin,
+{
+    #[inline]
+    fn into_pin(self) -> Pin<Cow<'a, B>> {
+        Pin::new(Cow::Borrowed(self))
+    }
+}
+
 impl<'a> IntoPin<Cow<'a, [u8]>> for Cow<'a, str> {
     #[inline]
     fn into_pin(self) -> Pin<Cow<'a, [u8]>> {
@@ -535,6 +743,14 @@ impl<'a> IntoPin<Cow<'a, [u8]>> for Cow<'a, str> {
     }
 }
 
+// Allocates only for a Cow::Borrowed, a Cow::Owned is moved out as is.
+impl<'a> IntoPin<String> for Cow<'a, str> {
+    #[inline]
+    fn into_pin(self) -> Pin<String> {
+        Pin::new(self.into_owned())
+    }
+}
+
 impl<'short, 'long> IntoPin<&'short [u8]> for &'short Cow<'long, str> {
     #[inline]
     fn into_pin(self) -> Pin<&'short [u8]> {
@@ -547,7 +763,23 @@ impl<'short, 'long> IntoPin<&'short [u8]> for &'short mut Cow<'long, str> {
     #[inline]
     fn into_pin(self) -> Pin<&'short [u8]> {
         // Asref into &str, then Asref into &[u8].
-        Pin::new((&*self).as_ref().as_ref())
+        Pin::new((*self).as_ref().as_ref())
+    }
+}
+
+// Without the nul terminator.
+impl<'short, 'long> IntoPin<&'short [u8]> for &'short Cow<'long, CStr> {
+    #[inline]
+    fn into_pin(self) -> Pin<&'short [u8]> {
+        Pin::new(self.to_bytes())
+    }
+}
+
+// Without the nul terminator.
+impl<'short, 'long> IntoPin<&'short [u8]> for &'short mut Cow<'long, CStr> {
+    #[inline]
+    fn into_pin(self) -> Pin<&'short [u8]> {
+        Pin::new(self.to_bytes())
     }
 }
 ///////////////////////////////////////////////
@@ -577,24 +809,31 @@ impl<'a, T: Unpin + ?Sized> IntoPin<&'a T> for &'a Arc<T> {
     }
 }
 
-impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Arc<&'long T> {
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Arc<&T> {
     #[inline]
     fn into_pin(self) -> Pin<&'short T> {
         Pin::new(self.as_ref())
     }
 }
 
-impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Arc<&'long mut T> {
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Pin<Arc<&T>> {
+    #[inline]
+    fn into_pin(self) -> Pin<&'short T> {
+        Pin::new(*self.as_ref().get_ref())
+    }
+}
+
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Arc<&mut T> {
     #[inline]
     fn into_pin(self) -> Pin<&'short T> {
         Pin::new(self.as_ref())
     }
 }
 
-impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short mut Arc<&'long mut T> {
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short T> for &'short mut Arc<&mut T> {
     #[inline]
     fn into_pin(self) -> Pin<&'short T> {
-        Pin::new(&**self)
+        Pin::new(**self)
     }
 }
 ///////////////////////////////////////////////
@@ -631,14 +870,14 @@ impl<'a, T: Unpin + ?Sized> IntoPin<&'a T> for Rc<&'a T> {
     }
 }
 
-impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Rc<&'long T> {
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short T> for &'short Rc<&T> {
     #[inline]
     fn into_pin(self) -> Pin<&'short T> {
         Pin::new(self.as_ref())
     }
 }
 
-impl<'short, 'long, T: Unpin + ?Sized> IntoPin<&'short T> for &'short mut Rc<&'long T> {
+impl<'short, T: Unpin + ?Sized> IntoPin<&'short T> for &'short mut Rc<&T> {
     #[inline]
     fn into_pin(self) -> Pin<&'short T> {
         Pin::new(self.as_ref())
@@ -721,13 +960,13 @@ impl<'a, T: Unpin> IntoPin<&'a mut T> for &'a mut Cell<T> {
     }
 }
 
-impl<'short, 'long, T: Unpin> IntoPin<&'short T> for &'short mut Cell<&'long T> {
+impl<'short, T: Unpin> IntoPin<&'short T> for &'short mut Cell<&T> {
     fn into_pin(self) -> Pin<&'short T> {
         Pin::new(self.get_mut())
     }
 }
 
-impl<'short, 'long, T: Unpin> IntoPin<&'short mut T> for &'short mut Cell<&'long mut T> {
+impl<'short, T: Unpin> IntoPin<&'short mut T> for &'short mut Cell<&mut T> {
     fn into_pin(self) -> Pin<&'short mut T> {
         Pin::new(self.get_mut())
     }
@@ -746,9 +985,66 @@ impl<'a, T: Unpin> IntoPin<&'a [Cell<T>]> for &'a mut Cell<[T]> {
         Pin::new(self.as_slice_of_cells())
     }
 }
+
+#[cfg(feature = "slice_of_cells")]
+impl<'a, T: Unpin, const N: usize> IntoPin<&'a [Cell<T>]> for &'a Cell<[T; N]> {
+    fn into_pin(self) -> Pin<&'a [Cell<T>]> {
+        let cell: &'a Cell<[T]> = self;
+        Pin::new(cell.as_slice_of_cells())
+    }
+}
+
+#[cfg(feature = "slice_of_cells")]
+impl<'a, T: Unpin, const N: usize> IntoPin<&'a [Cell<T>]> for &'a mut Cell<[T; N]> {
+    fn into_pin(self) -> Pin<&'a [Cell<T>]> {
+        let cell: &'a Cell<[T]> = self;
+        Pin::new(cell.as_slice_of_cells())
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// IOSLICE IMPL
+///////////////////////////////////////////////
+impl<'a> IntoPin<IoSlice<'a>> for IoSlice<'a> {
+    #[inline]
+    fn into_pin(self) -> Pin<Self> {
+        Pin::new(self)
+    }
+}
+
+impl<'a> IntoPin<IoSliceMut<'a>> for IoSliceMut<'a> {
+    #[inline]
+    fn into_pin(self) -> Pin<Self> {
+        Pin::new(self)
+    }
+}
+///////////////////////////////////////////////
+///////////////////////////////////////////////
+
+///////////////////////////////////////////////
+// ARRAY IMPL
+///////////////////////////////////////////////
+#[cfg(feature = "arrays")]
+impl<T: Unpin, const N: usize> IntoPin<Box<[T]>> for [T; N] {
+    #[inline]
+    fn into_pin(self) -> Pin<Box<[T]>> {
+        Pin::new(Box::new(self))
+    }
+}
+
+#[cfg(feature = "arrays")]
+impl<T: Unpin, const N: usize> IntoPin<Vec<T>> for [T; N] {
+    #[inline]
+    fn into_pin(self) -> Pin<Vec<T>> {
+        Pin::new(Vec::from(self))
+    }
+}
 ///////////////////////////////////////////////
 ///////////////////////////////////////////////
 
+#[cfg(feature = "arrays")]
 macro_rules! impl_array {
     ($size:expr $(,$sizes:expr)*) => (
         impl <'a, T: Unpin> IntoPin<&'a [T]> for &'a [T; $size] {
@@ -778,6 +1074,7 @@ macro_rules! impl_array {
     () => {}
 }
 
+#[cfg(feature = "arrays")]
 impl_array!(
     0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
     26, 27, 28, 29, 30, 31, 32
@@ -7,24 +7,24 @@ fn box_into_pin() {
 
     // &mut Box<T> to Pin<&mut Box<T>>
     {
-        let pin: Pin<&mut Box<u32>> = (&mut b).into_pin();
+        let _pin: Pin<&mut Box<u32>> = (&mut b).into_pin();
     }
 
     // &mut Box<T> to Pin<&mut T>
     {
-        let pin: Pin<&mut u32> = (&mut b).into_pin();
+        let _pin: Pin<&mut u32> = (&mut b).into_pin();
     }
 
     // &Box<T> to Pin<&T>
     {
-        let pin: Pin<&u32> = (&b).into_pin();
+        let _pin: Pin<&u32> = (&b).into_pin();
     }
     // &mut Box<T> to Pin<&T>
     {
-        let pin: Pin<&u32> = (&mut b).into_pin();
+        let _pin: Pin<&u32> = (&mut b).into_pin();
     }
 
-    let pinbox: Pin<Box<u32>> = b.into_pin();
+    let _pinbox: Pin<Box<u32>> = b.into_pin();
 }
 
 #[test]
@@ -98,7 +98,8 @@ fn pin_into_pin() {
 fn pinned_ref_to_pinned_ref() {
     use super::pinned::IntoPin;
 
-    fn quazr<'a, P: 'a, T: 'a>(x: P)
+    #[allow(clippy::multiple_bound_locations)]
+    fn quazr<'a, P: 'a, T: 'a>(_x: P)
     where
         P: IntoPin<&'a mut [T]>,
     {
@@ -117,14 +118,14 @@ fn pinned_ref_to_pinned_ref() {
 fn variants() {
     use super::pinned::IntoPin;
 
-    fn to_ref<'a, P, T: 'a>(x: P)
+    fn to_ref<'a, P, T: 'a>(_x: P)
     where
         P: IntoPin<&'a T>,
     {
 
     }
 
-    fn to_mut<'a, P, T: 'a>(x: P)
+    fn to_mut<'a, P, T: 'a>(_x: P)
     where
         P: IntoPin<&'a mut T>,
     {
@@ -146,7 +147,7 @@ fn variants() {
     to_ref::<_, u32>(pinref);
 
     // &PIN<&MUT T> TO PIN<&T>
-    let mut pin: Pin<&mut u32> = (&mut n).into_pin();
+    let pin: Pin<&mut u32> = (&mut n).into_pin();
     let pinref: &Pin<&mut u32> = &pin;
     to_ref::<_, u32>(pinref);
 
@@ -190,7 +191,7 @@ fn pinned_str_to_pinned_bytes() {
         R: Borrow<P>,
         P: IntoPin<&'a [u8]>,
     {
-        let f: Pin<&_> = (&x.borrow()).into_pin();
+        let _f: Pin<&_> = (&x.borrow()).into_pin();
     }
 
     let s = "hello";
@@ -205,7 +206,7 @@ fn pinned_str_to_pinned_bytes() {
 fn test_pinning() {
     use super::pinned::IntoPin;
 
-    fn quarck<'a, T>(x: T)
+    fn quarck<'a, T>(_x: T)
     where
         T: IntoPin<&'a [u8]>,
     {
@@ -221,3 +222,1862 @@ fn test_pinning() {
 
     quarck(&mut *b);
 }
+
+#[test]
+fn btreemap_into_pin_entries() {
+    use super::ext::MapPinExt;
+    use std::collections::BTreeMap;
+
+    let mut map = BTreeMap::new();
+    map.insert(3, String::from("three"));
+    map.insert(1, String::from("one"));
+    map.insert(2, String::from("two"));
+
+    let entries: Pin<Vec<(u32, String)>> = map.into_pin_entries();
+
+    let keys: Vec<u32> = entries.iter().map(|(k, _)| *k).collect();
+    assert_eq!(keys, [1, 2, 3]);
+    assert_eq!(entries[0].1, "one");
+    assert_eq!(entries[2].1, "three");
+}
+
+#[cfg(feature = "memchr")]
+#[test]
+fn pinned_bytes_memchr() {
+    use super::ext::PinSliceExt;
+    use super::pinned::IntoPin;
+
+    let v = vec![1u8, 2, 3, 4];
+    let pin: Pin<&[u8]> = (&v).into_pin();
+
+    assert_eq!(pin.memchr_pin(3), Some(2));
+    assert_eq!(pin.memchr_pin(9), None);
+}
+
+#[test]
+fn slice_of_refs_into_pin() {
+    use super::pinned::IntoPin;
+
+    fn consume<'a, 'b: 'a, P>(x: P) -> u32
+    where
+        P: IntoPin<&'a [&'b u32]>,
+    {
+        x.into_pin().iter().map(|n| **n).sum()
+    }
+
+    let (a, b, c) = (1, 2, 3);
+    let table: [&u32; 3] = [&a, &b, &c];
+
+    // &[&T; N] to Pin<&[&T]>
+    #[cfg(feature = "arrays")]
+    {
+        let pin: Pin<&[&u32]> = (&table).into_pin();
+        assert_eq!(*pin[1], 2);
+    }
+
+    // &[&T] to Pin<&[&T]>
+    {
+        let slice: &[&u32] = &table;
+        let pin: Pin<&[&u32]> = slice.into_pin();
+        assert_eq!(pin.len(), 3);
+    }
+
+    assert_eq!(consume(&table[..]), 6);
+    assert_eq!(consume(&table[..2]), 3);
+}
+
+#[test]
+fn borrowed_pin_into_owned_pin() {
+    use super::into_owned_pin;
+    use super::pinned::IntoPin;
+
+    // Pin<&str> to Pin<String>
+    let owned: Pin<String> = {
+        let s = String::from("pinned");
+        let pin: Pin<&str> = (&s).into_pin();
+        into_owned_pin(pin)
+    };
+    assert_eq!(&*owned, "pinned");
+
+    // Pin<&[u8]> to Pin<Vec<u8>>
+    let owned: Pin<Vec<u8>> = {
+        let v = vec![1u8, 2, 3];
+        let pin: Pin<&[u8]> = (&v).into_pin();
+        into_owned_pin(pin)
+    };
+    assert_eq!(*owned, [1, 2, 3]);
+}
+
+#[test]
+fn boxed_str_into_pinned_string() {
+    use super::pinned::IntoPin;
+
+    let b: Box<str> = Box::from("interned");
+    let pin: Pin<String> = b.into_pin();
+    assert_eq!(&*pin, "interned");
+
+    let mut s = Pin::into_inner(pin);
+    s.push('!');
+    assert_eq!(s, "interned!");
+}
+
+#[test]
+fn shared_slice_into_pin() {
+    use super::pinned::IntoPin;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let arc: Arc<[u32]> = Arc::from(vec![1, 2, 3]);
+
+    // &Arc<[T]> to Pin<&[T]>
+    {
+        let pin: Pin<&[u32]> = (&arc).into_pin();
+        assert_eq!(pin[2], 3);
+    }
+
+    // Arc<[T]> to Pin<Arc<[T]>>
+    let pin: Pin<Arc<[u32]>> = arc.into_pin();
+    assert_eq!(pin[0], 1);
+
+    let rc: Rc<[u32]> = Rc::from(vec![4, 5, 6]);
+
+    // &Rc<[T]> to Pin<&[T]>
+    {
+        let pin: Pin<&[u32]> = (&rc).into_pin();
+        assert_eq!(pin[1], 5);
+    }
+
+    // Rc<[T]> to Pin<Rc<[T]>>
+    let pin: Pin<Rc<[u32]>> = rc.into_pin();
+    assert_eq!(pin.len(), 3);
+}
+
+#[test]
+fn nonzero_into_pin() {
+    use super::pinned::IntoPin;
+    use std::num::NonZeroU16;
+
+    let n = NonZeroU16::new(7).unwrap();
+
+    // &NonZero to Pin<&NonZero>
+    {
+        let pin: Pin<&NonZeroU16> = (&n).into_pin();
+        assert_eq!(pin.get(), 7);
+    }
+
+    // NonZero to Pin<Box<NonZero>>
+    let pin: Pin<Box<NonZeroU16>> = n.into_pin();
+    assert_eq!(pin.get(), 7);
+}
+
+#[test]
+fn nested_box_into_pin() {
+    use super::pinned::IntoPin;
+
+    let mut b: Box<Box<u32>> = Box::new(Box::new(5));
+
+    // &Box<Box<T>> to Pin<&T>
+    {
+        let pin: Pin<&u32> = (&b).into_pin();
+        assert_eq!(*pin, 5);
+    }
+
+    // &mut Box<Box<T>> to Pin<&mut T>
+    {
+        let mut pin: Pin<&mut u32> = (&mut b).into_pin();
+        *pin = 6;
+    }
+    assert_eq!(**b, 6);
+}
+
+#[cfg(all(unix, feature = "unix_bytes"))]
+#[test]
+fn path_into_pinned_bytes() {
+    use super::pinned::IntoPin;
+    use std::path::{Path, PathBuf};
+
+    // &Path to Pin<&[u8]>
+    {
+        let path = Path::new("/tmp/pinned");
+        let pin: Pin<&[u8]> = path.into_pin();
+        assert_eq!(&*pin, b"/tmp/pinned");
+    }
+
+    // PathBuf to Pin<Vec<u8>>
+    let buf = PathBuf::from("/tmp/pinned");
+    let pin: Pin<Vec<u8>> = buf.into_pin();
+    assert_eq!(*pin, *b"/tmp/pinned");
+}
+
+#[cfg(feature = "slice_of_cells")]
+#[test]
+fn cell_slice_into_pinned_cells() {
+    use super::pinned::IntoPin;
+    use std::cell::Cell;
+
+    let cell = Cell::new([1u8, 2, 3, 4]);
+    let unsized_cell: &Cell<[u8]> = &cell;
+
+    let pin: Pin<&[Cell<u8>]> = unsized_cell.into_pin();
+    assert_eq!(pin.len(), 4);
+
+    pin[2].set(9);
+    assert_eq!(cell.get(), [1, 2, 9, 4]);
+}
+
+#[test]
+fn hashmap_pin_value_mut() {
+    use super::ext::MapKeyPinExt;
+    use std::collections::{BTreeMap, HashMap};
+
+    let mut map = HashMap::new();
+    map.insert(String::from("a"), 1u32);
+
+    {
+        let mut pin: Pin<&mut u32> = map.pin_value_mut("a").unwrap();
+        *pin += 1;
+    }
+    assert_eq!(map["a"], 2);
+
+    assert!(map.pin_value_mut("b").is_none());
+
+    let mut sorted = BTreeMap::new();
+    sorted.insert(vec![1u8], 0u32);
+    *sorted.pin_value_mut(&[1u8][..]).unwrap() += 1;
+    assert_eq!(sorted[&[1u8][..]], 1);
+}
+
+#[test]
+fn cell_ref_into_pinned_cell() {
+    use super::pinned::IntoPin;
+    use std::cell::Cell;
+
+    let cell = Cell::new(1u32);
+
+    {
+        let pin: Pin<&Cell<u32>> = (&cell).into_pin();
+        pin.set(2);
+        assert_eq!(pin.get(), 2);
+    }
+    assert_eq!(cell.get(), 2);
+}
+
+#[test]
+fn slice_into_pin_chunks() {
+    use super::ext::SlicePinExt;
+
+    let s: &[u32] = &[1, 2, 3, 4, 5];
+    let chunks: Vec<Pin<&[u32]>> = s.into_pin_chunks(2).collect();
+
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(*chunks[0], [1, 2]);
+    assert_eq!(*chunks[1], [3, 4]);
+    assert_eq!(*chunks[2], [5]);
+}
+
+#[test]
+#[should_panic]
+fn slice_into_pin_chunks_zero() {
+    use super::ext::SlicePinExt;
+
+    let s: &[u32] = &[1, 2, 3];
+    let _ = s.into_pin_chunks(0);
+}
+
+#[test]
+fn result_into_pin() {
+    use super::ext::ResultPinExt;
+    use super::pinned::IntoPin;
+
+    // Ok(&mut T) to Ok(Pin<&mut T>)
+    {
+        let mut n = 1u32;
+        let res: Result<&mut u32, &str> = Ok(&mut n);
+        let mut pin: Pin<&mut u32> = res.into_pin_ok().unwrap();
+        *pin = 2;
+        assert_eq!(n, 2);
+    }
+
+    // Err(E) is passed through
+    {
+        let res: Result<&mut u32, &str> = Err("failed");
+        assert_eq!(res.into_pin_ok().unwrap_err(), "failed");
+    }
+
+    // Result<T, E> to Pin<Box<Result<T, E>>>
+    let res: Result<u32, &str> = Ok(3);
+    let pin: Pin<Box<Result<u32, &str>>> = res.into_pin();
+    assert_eq!(*pin, Ok(3));
+
+    let res: Result<u32, &str> = Err("failed");
+    let pin: Pin<Box<Result<u32, &str>>> = res.into_pin();
+    assert_eq!(*pin, Err("failed"));
+}
+
+#[test]
+fn ref_into_pinned_borrowed_cow() {
+    use super::pinned::IntoPin;
+    use std::borrow::Cow;
+
+    let s = "borrowed";
+    let pin: Pin<Cow<str>> = s.into_pin();
+
+    assert_eq!(&*pin, "borrowed");
+    match Pin::into_inner(pin) {
+        Cow::Borrowed(b) => assert_eq!(b, "borrowed"),
+        Cow::Owned(_) => panic!("expected a borrowed Cow"),
+    }
+
+    let bytes: &[u8] = &[1, 2, 3];
+    let pin: Pin<Cow<[u8]>> = bytes.into_pin();
+    assert_eq!(*pin, [1, 2, 3]);
+}
+
+#[cfg(feature = "arrays")]
+#[test]
+fn array_into_pinned_owned_slice() {
+    use super::pinned::IntoPin;
+
+    // [T; N] to Pin<Box<[T]>>
+    let pin: Pin<Box<[u8]>> = [1u8, 2, 3, 4].into_pin();
+    assert_eq!(*pin, [1, 2, 3, 4]);
+
+    // [T; N] to Pin<Vec<T>>
+    let pin: Pin<Vec<u8>> = [1u8, 2, 3, 4].into_pin();
+    assert_eq!(*pin, [1, 2, 3, 4]);
+}
+
+#[test]
+fn cow_into_pinned_mut() {
+    use super::pinned::IntoPin;
+    use std::borrow::Cow;
+
+    let n = 1u32;
+    let mut cow: Cow<u32> = Cow::Borrowed(&n);
+
+    {
+        let mut pin: Pin<&mut u32> = (&mut cow).into_pin();
+        *pin = 2;
+    }
+
+    match cow {
+        Cow::Owned(o) => assert_eq!(o, 2),
+        Cow::Borrowed(_) => panic!("expected an owned Cow"),
+    }
+    assert_eq!(n, 1);
+}
+
+#[test]
+fn range_into_pin() {
+    use super::pinned::IntoPin;
+    use std::ops::{Range, RangeInclusive};
+
+    let range = 0..10;
+    let inclusive = 0..=10;
+
+    // &Range<T> to Pin<&Range<T>>
+    {
+        let pin: Pin<&Range<u32>> = (&range).into_pin();
+        assert_eq!((pin.start, pin.end), (0, 10));
+    }
+
+    // &RangeInclusive<T> to Pin<&RangeInclusive<T>>
+    {
+        let pin: Pin<&RangeInclusive<u32>> = (&inclusive).into_pin();
+        assert_eq!((*pin.start(), *pin.end()), (0, 10));
+    }
+
+    // Range<T> to Pin<Box<Range<T>>>
+    let pin: Pin<Box<Range<u32>>> = range.into_pin();
+    assert_eq!(pin.len(), 10);
+}
+
+#[test]
+fn rc_refcell_into_pin() {
+    use super::ext::RefCellPinExt;
+    use super::pinned::IntoPin;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let rc = Rc::new(RefCell::new(1u32));
+
+    // &Rc<RefCell<T>> to Pin<&RefCell<T>>
+    {
+        let pin: Pin<&RefCell<u32>> = (&rc).into_pin();
+        *pin.borrow_mut() = 2;
+    }
+
+    {
+        let mut guard = rc.pin_borrow_mut();
+        let mut pin: Pin<&mut u32> = guard.as_mut();
+        *pin += 1;
+    }
+
+    assert_eq!(*rc.pin_borrow(), 3);
+}
+
+#[test]
+fn vec_into_pinned_shared_slice() {
+    use super::pinned::IntoPin;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    // Vec<T> to Pin<Arc<[T]>>
+    let pin: Pin<Arc<[u32]>> = vec![1, 2, 3].into_pin();
+    let shared = Pin::clone(&pin);
+    assert_eq!(*pin, [1, 2, 3]);
+    assert_eq!(*shared, [1, 2, 3]);
+
+    // Vec<T> to Pin<Rc<[T]>>
+    let pin: Pin<Rc<[u32]>> = vec![4, 5, 6].into_pin();
+    let shared = Pin::clone(&pin);
+    assert_eq!(*shared, [4, 5, 6]);
+}
+
+#[test]
+fn once_cells_pin_get() {
+    use super::ext::OncePinExt;
+    use std::cell::OnceCell;
+    use std::sync::OnceLock;
+
+    let cell = OnceCell::new();
+    assert!(cell.pin_get().is_none());
+    cell.set(String::from("once")).unwrap();
+    let pin: Pin<&String> = cell.pin_get().unwrap();
+    assert_eq!(&**pin, "once");
+
+    let lock = OnceLock::new();
+    assert!(lock.pin_get().is_none());
+    lock.set(1u32).unwrap();
+    let pin: Pin<&u32> = lock.pin_get().unwrap();
+    assert_eq!(*pin, 1);
+}
+
+#[test]
+fn deref_newtype_pin_deref() {
+    use super::ext::DerefPinExt;
+    use std::ops::{Deref, DerefMut};
+
+    struct Wrapper(Vec<u8>);
+
+    impl Deref for Wrapper {
+        type Target = [u8];
+
+        fn deref(&self) -> &[u8] {
+            &self.0
+        }
+    }
+
+    impl DerefMut for Wrapper {
+        fn deref_mut(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+    }
+
+    let mut w = Wrapper(vec![1, 2, 3]);
+
+    {
+        let mut pin: Pin<&mut [u8]> = w.pin_deref_mut();
+        pin[0] = 0;
+    }
+
+    let pin: Pin<&[u8]> = w.pin_deref();
+    assert_eq!(*pin, [0, 2, 3]);
+}
+
+#[test]
+fn slice_into_pin_split_at() {
+    use super::ext::{SliceMutPinExt, SlicePinExt};
+
+    let s: &[u32] = &[1, 2, 3];
+
+    let (left, right) = s.into_pin_split_at(1);
+    assert_eq!((&*left, &*right), (&[1][..], &[2, 3][..]));
+
+    let (left, right) = s.into_pin_split_at(0);
+    assert_eq!((left.len(), right.len()), (0, 3));
+
+    let (left, right) = s.into_pin_split_at(3);
+    assert_eq!((left.len(), right.len()), (3, 0));
+
+    let mut v = [1u32, 2, 3, 4];
+    {
+        let (mut left, mut right) = (&mut v[..]).into_pin_split_at_mut(2);
+        left[1] = 0;
+        right[1] = 0;
+    }
+    assert_eq!(v, [1, 0, 3, 0]);
+}
+
+#[test]
+#[should_panic]
+fn slice_into_pin_split_at_out_of_bounds() {
+    use super::ext::SlicePinExt;
+
+    let s: &[u32] = &[1, 2, 3];
+    let _ = s.into_pin_split_at(4);
+}
+
+#[test]
+fn mut_str_into_pinned_osstr_and_path() {
+    use super::pinned::IntoPin;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    let mut s = String::from("dir/file");
+    let m: &mut str = s.as_mut_str();
+
+    {
+        let os: Pin<&OsStr> = (&mut *m).into_pin();
+        assert_eq!(&*os, OsStr::new("dir/file"));
+    }
+    {
+        let path: Pin<&Path> = (&mut *m).into_pin();
+        assert_eq!(path.file_name(), Some(OsStr::new("file")));
+    }
+
+    m.make_ascii_uppercase();
+    assert_eq!(s, "DIR/FILE");
+}
+
+#[test]
+fn nested_vec_into_pin_rows() {
+    use super::ext::RowsPinExt;
+
+    let mut matrix = vec![vec![1u32, 2], vec![]];
+    matrix.push(vec![3]);
+
+    let rows: Vec<Pin<&[u32]>> = matrix.into_pin_rows().collect();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(*rows[0], [1, 2]);
+    assert!(rows[1].is_empty());
+    assert_eq!(*rows[2], [3]);
+}
+
+#[test]
+fn uninit_boxed_slice_into_pin() {
+    use super::pinned::IntoPin;
+    use std::mem::MaybeUninit;
+
+    let mut buf: Box<[MaybeUninit<u8>]> = Box::new_uninit_slice(4);
+
+    {
+        let mut pin: Pin<&mut [MaybeUninit<u8>]> = (&mut buf).into_pin();
+        for (i, slot) in pin.iter_mut().enumerate() {
+            slot.write(i as u8);
+        }
+    }
+
+    let buf: Pin<Box<[MaybeUninit<u8>]>> = buf.into_pin();
+    let buf = unsafe { Pin::into_inner(buf).assume_init() };
+    assert_eq!(*buf, [0, 1, 2, 3]);
+}
+
+#[cfg(all(unix, feature = "unix_bytes"))]
+#[test]
+fn osstr_into_pinned_bytes() {
+    use super::pinned::IntoPin;
+    use std::ffi::{OsStr, OsString};
+    use std::os::unix::ffi::OsStringExt;
+
+    let os = OsStr::new("pinned");
+    let pin: Pin<&[u8]> = os.into_pin();
+    assert_eq!(&*pin, b"pinned");
+
+    // not valid UTF-8
+    let raw = OsString::from_vec(vec![0x66, 0x6f, 0xff]);
+    let pin: Pin<&[u8]> = raw.as_os_str().into_pin();
+    assert_eq!(*pin, [0x66, 0x6f, 0xff]);
+}
+
+#[test]
+fn pinned_vec_into_pinned_slice() {
+    use super::pinned::IntoPin;
+
+    let mut pin: Pin<Vec<u32>> = vec![1, 2, 3].into_pin();
+
+    // &Pin<Vec<T>> to Pin<&[T]>
+    {
+        let slice: Pin<&[u32]> = (&pin).into_pin();
+        assert_eq!(slice[1], 2);
+    }
+
+    // &mut Pin<Vec<T>> to Pin<&mut [T]>
+    {
+        let mut slice: Pin<&mut [u32]> = (&mut pin).into_pin();
+        slice[1] = 0;
+    }
+    assert_eq!(*pin, [1, 0, 3]);
+}
+
+#[test]
+fn tuple_of_refs_into_pin_tuple() {
+    use super::ext::TuplePinExt;
+
+    let (a, b, mut c, d) = (1u8, String::from("two"), [3u32], Box::new(4u64));
+
+    {
+        let (a, b): (Pin<&u8>, Pin<&String>) = (&a, &b).into_pin_tuple();
+        assert_eq!((*a, b.as_str()), (1, "two"));
+    }
+
+    {
+        let (a, b, mut c, d) = (&a, &b, &mut c[..], &d).into_pin_tuple();
+        c[0] += 1;
+        assert_eq!((*a, b.as_str(), c[0], **d), (1, "two", 4, 4));
+    }
+    assert_eq!(c, [4]);
+}
+
+#[test]
+fn binary_heap_pin_into_sorted_vec() {
+    use super::ext::HeapPinExt;
+    use std::collections::BinaryHeap;
+
+    let mut heap = BinaryHeap::new();
+    for n in [5u32, 1, 4, 2, 3, 2] {
+        heap.push(n);
+    }
+
+    let sorted: Pin<Vec<u32>> = heap.pin_into_sorted_vec();
+    assert_eq!(*sorted, [1, 2, 2, 3, 4, 5]);
+
+    let empty: Pin<Vec<u32>> = BinaryHeap::new().pin_into_sorted_vec();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn slice_cow_into_pin() {
+    use super::pinned::IntoPin;
+    use std::borrow::Cow;
+
+    let data = [String::from("a"), String::from("b")];
+
+    // Cow::Borrowed(&[T]) to Pin<&[T]>
+    let borrowed: Cow<[String]> = Cow::Borrowed(&data);
+    {
+        let pin: Pin<&[String]> = (&borrowed).into_pin();
+        assert_eq!(pin[1], "b");
+    }
+
+    // Cow::Owned(Vec<T>) to Pin<&[T]>
+    let mut owned: Cow<[String]> = Cow::Owned(vec![String::from("c")]);
+    {
+        let pin: Pin<&[String]> = (&mut owned).into_pin();
+        assert_eq!(pin[0], "c");
+    }
+
+    // Cow<[T]> to Pin<Cow<[T]>>
+    let pin: Pin<Cow<[String]>> = borrowed.into_pin();
+    assert_eq!(pin.len(), 2);
+    let pin: Pin<Cow<[String]>> = owned.into_pin();
+    assert!(matches!(Pin::into_inner(pin), Cow::Owned(_)));
+
+    // Cow<str> still pins to bytes as well as to str
+    let s: Cow<str> = Cow::Borrowed("cow");
+    let bytes: Pin<&[u8]> = (&s).into_pin();
+    let text: Pin<&str> = (&s).into_pin();
+    assert_eq!(&*bytes, text.as_bytes());
+}
+
+#[test]
+fn mut_slice_into_pinned_shared_slice() {
+    use super::pinned::IntoPin;
+
+    fn first<'a, P: IntoPin<&'a [u32]>>(p: P) -> u32 {
+        p.into_pin()[0]
+    }
+
+    let mut v = [7u32, 8, 9];
+    let slice: &mut [u32] = &mut v;
+
+    let pin: Pin<&[u32]> = (&mut *slice).into_pin();
+    assert_eq!(pin[2], 9);
+
+    assert_eq!(first(slice), 7);
+}
+
+#[test]
+fn refcell_ref_into_pin() {
+    use super::pinned::IntoPin;
+    use std::cell::RefCell;
+
+    let cell = RefCell::new(1u32);
+
+    let pin: Pin<&RefCell<u32>> = (&cell).into_pin();
+    *pin.borrow_mut() += 1;
+
+    assert_eq!(*cell.borrow(), 2);
+}
+
+#[test]
+fn vec_pin_iter_mut() {
+    use super::ext::SliceMutPinExt;
+
+    fn visit(mut p: Pin<&mut u32>) {
+        *p += 1;
+    }
+
+    let mut v = vec![1u32, 2, 3];
+    v.pin_iter_mut().for_each(visit);
+    assert_eq!(v, [2, 3, 4]);
+
+    let mut a = [0u32; 2];
+    for p in a[..].pin_iter_mut() {
+        visit(p);
+    }
+    assert_eq!(a, [1, 1]);
+
+    let mut empty: Vec<u32> = Vec::new();
+    assert_eq!(empty.pin_iter_mut().count(), 0);
+}
+
+#[test]
+fn box_into_pinned_shared() {
+    use super::pinned::IntoPin;
+    use super::{box_into_pin_arc, box_into_pin_rc};
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let arc: Pin<Arc<u32>> = box_into_pin_arc(Box::new(7u32));
+    let clone = Pin::clone(&arc);
+    assert_eq!((*arc, *clone), (7, 7));
+    assert_eq!(Arc::strong_count(&Pin::into_inner(arc)), 2);
+
+    let rc: Pin<Rc<[u8]>> = box_into_pin_rc(Box::<[u8]>::from(&b"rc"[..]));
+    assert_eq!(*rc, *b"rc");
+
+    // the value-to-smartpointer impl is the only one that applies to boxes, so this is not ambiguous
+    let nested: Pin<Arc<_>> = Box::new(1u32).into_pin();
+    assert_eq!(**nested, 1);
+}
+
+#[test]
+fn str_into_pinned_owned() {
+    use super::pinned::IntoPin;
+
+    let boxed: Pin<Box<str>> = "owned".into_pin();
+    assert_eq!(&*boxed, "owned");
+
+    let string: Pin<String> = "owned".into_pin();
+    assert_eq!(&*string, "owned");
+    assert_ne!(string.as_ptr(), boxed.as_ptr());
+}
+
+#[test]
+fn nested_array_into_pin_flat() {
+    use super::ext::FlatPinExt;
+
+    let matrix: &[[u8; 2]; 2] = &[[1, 2], [3, 4]];
+    let flat: Pin<&[u8]> = matrix.into_pin_flat();
+    assert_eq!(*flat, [1, 2, 3, 4]);
+
+    let no_rows: &[[u8; 3]; 0] = &[];
+    assert!(no_rows.into_pin_flat().is_empty());
+
+    let no_cols: &[[u8; 0]; 3] = &[[], [], []];
+    assert!(no_cols.into_pin_flat().is_empty());
+}
+
+#[test]
+fn path_pin_components() {
+    use super::ext::PathPinExt;
+    use std::ffi::OsStr;
+    use std::path::{Path, MAIN_SEPARATOR_STR};
+
+    let path = Path::new(MAIN_SEPARATOR_STR).join("a").join("b").join("c");
+    let components: Vec<Pin<&OsStr>> = path.pin_components().collect();
+
+    let names: Vec<&OsStr> = components.iter().map(|c| &**c).collect();
+    assert_eq!(names[1..], ["a", "b", "c"]);
+    assert_eq!(names.len(), 4);
+    assert!(!names[0].is_empty());
+}
+
+#[test]
+fn vec_drain_into_pin() {
+    use super::ext::VecPinExt;
+
+    let mut pool: Vec<u32> = Vec::with_capacity(8);
+
+    for round in 0..2 {
+        pool.extend([round, round + 1]);
+        let capacity = pool.capacity();
+
+        let snapshot: Pin<Box<[u32]>> = pool.drain_into_pin();
+        assert_eq!(*snapshot, [round, round + 1]);
+
+        assert!(pool.is_empty());
+        assert_eq!(pool.capacity(), capacity);
+    }
+}
+
+#[test]
+fn pinned_slice_into_pin_chunks_mut() {
+    use super::ext::PinSliceMutExt;
+    use super::pinned::IntoPin;
+
+    let mut buf = [1u8; 7];
+    let pin: Pin<&mut [u8]> = (&mut buf[..]).into_pin();
+
+    let mut lens = Vec::new();
+    for mut chunk in pin.into_pin_chunks_mut(3) {
+        lens.push(chunk.len());
+        chunk.fill(0);
+    }
+    assert_eq!(lens, [3, 3, 1]);
+    assert_eq!(buf, [0; 7]);
+}
+
+#[test]
+#[should_panic]
+fn pinned_slice_into_pin_chunks_mut_zero() {
+    use super::ext::PinSliceMutExt;
+    use super::pinned::IntoPin;
+
+    let mut buf = [1u8; 2];
+    let pin: Pin<&mut [u8]> = (&mut buf[..]).into_pin();
+    let _ = pin.into_pin_chunks_mut(0);
+}
+
+#[test]
+fn arc_mutex_pin_lock() {
+    use super::ext::MutexPinExt;
+    use std::panic::{self, AssertUnwindSafe};
+    use std::sync::{Arc, Mutex};
+
+    let shared = Arc::new(Mutex::new(0u32));
+
+    {
+        let mut guard: Pin<std::sync::MutexGuard<u32>> = shared.pin_lock().unwrap();
+        *guard += 1;
+    }
+    assert_eq!(*shared.lock().unwrap(), 1);
+
+    // a poisoned mutex still hands out the pinned guard through the error.
+    // resume_unwind skips the panic hook, so poisoning it prints nothing.
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| {
+        let _guard = shared.pin_lock().unwrap();
+        panic::resume_unwind(Box::new("poison"));
+    }));
+
+    let mut guard = shared.pin_lock().unwrap_err().into_inner();
+    *guard += 1;
+    assert_eq!(*guard, 2);
+}
+
+#[test]
+fn pairs_pin_values_mut() {
+    use super::ext::PairsPinExt;
+    use super::pinned::IntoPin;
+
+    let mut pairs = [(1u8, 10u32), (2, 20), (3, 30)];
+    let slice: &mut [(u8, u32)] = &mut pairs;
+
+    // &mut [(K, V)] to Pin<&mut [(K, V)]>
+    {
+        let mut pin: Pin<&mut [(u8, u32)]> = (&mut *slice).into_pin();
+        pin[0].0 = 0;
+    }
+
+    for mut value in slice.pin_values_mut() {
+        *value += 1;
+    }
+    assert_eq!(pairs, [(0, 11), (2, 21), (3, 31)]);
+}
+
+#[test]
+fn net_addrs_into_pin() {
+    use super::pinned::IntoPin;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    fn port<'a, P: IntoPin<&'a SocketAddr>>(p: P) -> u16 {
+        p.into_pin().port()
+    }
+
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 8080);
+
+    let pin: Pin<&SocketAddr> = (&addr).into_pin();
+    assert_eq!(pin.port(), 8080);
+    assert_eq!(port(&addr), 8080);
+
+    let boxed: Pin<Box<SocketAddr>> = addr.into_pin();
+    let arc: Pin<Arc<SocketAddr>> = addr.into_pin();
+    let rc: Pin<Rc<SocketAddr>> = addr.into_pin();
+    assert_eq!((*boxed, *arc, *rc), (addr, addr, addr));
+
+    let ip = addr.ip();
+    let ip: Pin<&IpAddr> = (&ip).into_pin();
+    assert!(ip.is_loopback());
+
+    let v6 = Ipv6Addr::LOCALHOST;
+    let pin: Pin<&Ipv6Addr> = (&v6).into_pin();
+    assert!(pin.is_loopback());
+}
+
+#[test]
+fn boxed_slice_into_pinned_slice() {
+    use super::pinned::IntoPin;
+
+    let mut b: Box<[u8]> = Box::new([1, 2, 3]);
+
+    {
+        let mut pin: Pin<&mut [u8]> = (&mut b).into_pin();
+        pin.reverse();
+    }
+
+    let pin: Pin<&[u8]> = (&b).into_pin();
+    assert_eq!(*pin, [3, 2, 1]);
+}
+
+#[test]
+fn phantom_data_into_pin() {
+    use super::pinned::IntoPin;
+    use std::marker::PhantomData;
+
+    let marker: PhantomData<u8> = PhantomData;
+
+    let pin: Pin<&PhantomData<u8>> = (&marker).into_pin();
+    assert_eq!(*pin, PhantomData);
+
+    let boxed: Pin<Box<PhantomData<u8>>> = marker.into_pin();
+    assert_eq!(*boxed, PhantomData);
+}
+
+#[test]
+fn pinned_mut_pin_as_mut() {
+    use super::ext::PinMutExt;
+    use super::pinned::IntoPin;
+
+    let mut v = vec![0u8; 3];
+    let mut pin: Pin<&mut Vec<u8>> = (&mut v).into_pin();
+
+    {
+        let mut bytes: Pin<&mut [u8]> = pin.pin_as_mut();
+        bytes.copy_from_slice(b"abc");
+    }
+    pin.push(b'd');
+
+    assert_eq!(v, *b"abcd");
+}
+
+#[test]
+fn format_args_into_pin() {
+    use super::pinned::IntoPin;
+    use std::fmt::{Arguments, Write};
+
+    fn render(args: Pin<&Arguments>) -> String {
+        let mut out = String::new();
+        out.write_fmt(*args).unwrap();
+        out
+    }
+
+    let s = {
+        let args = format_args!("x={}", 1);
+        render((&args).into_pin())
+    };
+    assert_eq!(s, "x=1");
+}
+
+#[test]
+fn custom_pointer_impl_into_pin() {
+    use super::pinned::IntoPin;
+    use std::ops::{Deref, DerefMut};
+
+    struct Shared<T>(std::rc::Rc<T>);
+
+    impl<T> Deref for Shared<T> {
+        type Target = T;
+
+        fn deref(&self) -> &T {
+            &self.0
+        }
+    }
+
+    struct Owned<T>(Vec<T>);
+
+    impl<T> Deref for Owned<T> {
+        type Target = [T];
+
+        fn deref(&self) -> &[T] {
+            &self.0
+        }
+    }
+
+    impl<T> DerefMut for Owned<T> {
+        fn deref_mut(&mut self) -> &mut [T] {
+            &mut self.0
+        }
+    }
+
+    impl_into_pin!(Shared<T>);
+    impl_into_pin!(Owned<T>);
+
+    let mut shared = Shared(std::rc::Rc::new(1u32));
+    let pin: Pin<&u32> = (&shared).into_pin();
+    assert_eq!(*pin, 1);
+    let pin: Pin<&u32> = (&mut shared).into_pin();
+    assert_eq!(*pin, 1);
+
+    let mut owned = Owned(vec![1u8, 2]);
+    {
+        let mut pin: Pin<&mut [u8]> = (&mut owned).into_pin();
+        pin[0] = 0;
+    }
+    let pin: Pin<&[u8]> = (&owned).into_pin();
+    assert_eq!(*pin, [0, 2]);
+}
+
+#[test]
+fn vec_into_pin_halves_mut() {
+    use super::ext::SliceMutPinExt;
+
+    let mut v = vec![0u32; 5];
+    {
+        let (mut left, mut right) = v.into_pin_halves_mut();
+        assert_eq!((left.len(), right.len()), (2, 3));
+        left.fill(1);
+        right.fill(2);
+    }
+    assert_eq!(v, [1, 1, 2, 2, 2]);
+
+    let mut empty: Vec<u32> = Vec::new();
+    let (left, right) = empty.into_pin_halves_mut();
+    assert!(left.is_empty() && right.is_empty());
+}
+
+#[test]
+fn str_and_cow_into_pinned_cow() {
+    use super::pinned::IntoPin;
+    use std::borrow::Cow;
+
+    fn is_borrowed<'a, P: IntoPin<Cow<'a, str>>>(p: P) -> bool {
+        matches!(Pin::into_inner(p.into_pin()), Cow::Borrowed(_))
+    }
+
+    assert!(is_borrowed("borrowed"));
+    assert!(is_borrowed(Cow::Borrowed("borrowed")));
+    assert!(!is_borrowed(Cow::Owned(String::from("owned"))));
+}
+
+#[test]
+fn asref_pin_as_ref() {
+    use super::ext::AsRefPinExt;
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    fn pinned_len<S: AsRef<[u8]> + ?Sized>(s: &S) -> usize {
+        s.pin_as_ref::<[u8]>().len()
+    }
+
+    let s = String::from("four");
+    let bytes: Pin<&[u8]> = s.pin_as_ref();
+    assert_eq!(*bytes, *b"four");
+
+    let array = [1u8, 2, 3, 4];
+    let slice: Pin<&[u8]> = array.pin_as_ref();
+    assert_eq!(*slice, [1, 2, 3, 4]);
+
+    let path: Pin<&Path> = "a/b".pin_as_ref();
+    let os: Pin<&OsStr> = (*path).pin_as_ref();
+    assert_eq!(&*os, "a/b");
+
+    assert_eq!(pinned_len(&s), 4);
+    assert_eq!(pinned_len(&array), 4);
+    assert_eq!(pinned_len("str"), 3);
+}
+
+#[test]
+fn bufreader_into_pin() {
+    use super::ext::BufReaderPinExt;
+    use super::pinned::IntoPin;
+    use std::io::{BufRead, BufReader, Cursor, Read};
+
+    let mut reader = BufReader::new(Cursor::new(b"line\nrest".to_vec()));
+
+    // &mut BufReader<R> to Pin<&mut BufReader<R>>
+    {
+        let mut pin: Pin<&mut BufReader<Cursor<Vec<u8>>>> = (&mut reader).into_pin();
+        let mut line = String::new();
+        pin.read_line(&mut line).unwrap();
+        assert_eq!(line, "line\n");
+    }
+
+    // &mut BufReader<R> to Pin<&BufReader<R>>
+    {
+        let pin: Pin<&BufReader<Cursor<Vec<u8>>>> = (&mut reader).into_pin();
+        assert_eq!(pin.buffer(), b"rest");
+    }
+
+    let mut inner: Pin<Box<Cursor<Vec<u8>>>> = BufReader::new(Cursor::new(b"abc".to_vec())).into_pin_inner();
+    let mut s = String::new();
+    inner.read_to_string(&mut s).unwrap();
+    assert_eq!(s, "abc");
+}
+
+#[test]
+fn containers_as_pin_slice() {
+    use super::ext::AsRefPinExt;
+
+    fn bump<S: AsMut<[u32]> + ?Sized>(s: &mut S) {
+        for n in s.as_pin_slice_mut().iter_mut() {
+            *n += 1;
+        }
+    }
+
+    let mut v = vec![1u32, 2];
+    let mut b: Box<[u32]> = Box::new([3, 4]);
+    let mut a = [5u32, 6];
+
+    bump(&mut v);
+    bump(&mut b);
+    bump(&mut a);
+    bump(&mut a[..1]);
+
+    assert_eq!(*v.as_pin_slice(), [2, 3]);
+    assert_eq!(*b.as_pin_slice(), [4, 5]);
+    assert_eq!(*a.as_pin_slice(), [7, 7]);
+}
+
+#[test]
+fn cstr_cow_into_pin() {
+    use super::pinned::IntoPin;
+    use std::borrow::Cow;
+    use std::ffi::{CStr, CString};
+
+    let c = CStr::from_bytes_with_nul(b"borrowed\0").unwrap();
+    let borrowed: Cow<CStr> = Cow::Borrowed(c);
+    let mut owned: Cow<CStr> = Cow::Owned(CString::new("owned").unwrap());
+
+    // &Cow<CStr> to Pin<&CStr>
+    {
+        let pin: Pin<&CStr> = (&borrowed).into_pin();
+        assert_eq!(pin.to_bytes(), b"borrowed");
+        let pin: Pin<&CStr> = (&mut owned).into_pin();
+        assert_eq!(pin.to_bytes(), b"owned");
+    }
+
+    // &Cow<CStr> to Pin<&[u8]>
+    {
+        let pin: Pin<&[u8]> = (&borrowed).into_pin();
+        assert_eq!(&*pin, b"borrowed");
+        let pin: Pin<&[u8]> = (&mut owned).into_pin();
+        assert_eq!(&*pin, b"owned");
+    }
+
+    // Cow<CStr> to Pin<Cow<CStr>>
+    let pin: Pin<Cow<CStr>> = owned.into_pin();
+    assert!(matches!(Pin::into_inner(pin), Cow::Owned(_)));
+}
+
+#[test]
+fn array_pinned_without_allocation() {
+    use super::pinned::IntoPin;
+
+    let mut array = [1u32, 2, 3, 4];
+    let ptr = array.as_ptr();
+
+    let mut pin: Pin<&mut [u32; 4]> = (&mut array).into_pin();
+    assert_eq!(pin.as_ptr(), ptr);
+    pin[0] = 0;
+
+    let shared: Pin<&[u32; 4]> = (&mut array).into_pin();
+    assert_eq!(shared.as_ptr(), ptr);
+    assert_eq!(*shared, [0, 2, 3, 4]);
+
+    let pin: Pin<&[u32; 4]> = (&array).into_pin();
+    assert_eq!(pin.as_ptr(), ptr);
+    assert_eq!(pin[3], 4);
+    assert_eq!(pin.len(), 4);
+}
+
+#[test]
+fn iterator_collect_pin() {
+    use super::collect_pin;
+
+    let pin: Pin<Vec<String>> = collect_pin(["a", "b"].iter().map(|s| s.to_string()));
+    assert_eq!(*pin, ["a", "b"]);
+
+    let empty: Pin<Vec<u8>> = collect_pin(None);
+    assert!(empty.is_empty());
+}
+
+#[cfg(feature = "slice_of_cells")]
+#[test]
+fn cell_array_into_pinned_cells() {
+    use super::pinned::IntoPin;
+    use std::cell::Cell;
+
+    let mut cell = Cell::new([1u8, 2, 3, 4]);
+
+    {
+        let pin: Pin<&[Cell<u8>]> = (&cell).into_pin();
+        pin[2].set(0);
+    }
+    {
+        let pin: Pin<&[Cell<u8>]> = (&mut cell).into_pin();
+        assert_eq!(pin.len(), 4);
+    }
+
+    assert_eq!(cell.get(), [1, 2, 0, 4]);
+}
+
+#[test]
+fn weak_upgrade_pin() {
+    use super::ext::WeakPinExt;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let arc = Arc::new(1u32);
+    let weak = Arc::downgrade(&arc);
+    {
+        let pin: Pin<Arc<u32>> = weak.upgrade_pin().unwrap();
+        assert_eq!(*pin, 1);
+    }
+    drop(arc);
+    assert!(weak.upgrade_pin().is_none());
+
+    let rc: Rc<str> = Rc::from("rc");
+    let weak = Rc::downgrade(&rc);
+    {
+        let pin: Pin<Rc<str>> = weak.upgrade_pin().unwrap();
+        assert_eq!(&*pin, "rc");
+    }
+    drop(rc);
+    assert!(weak.upgrade_pin().is_none());
+}
+
+#[test]
+fn os_strings_pin_args() {
+    use super::ext::ArgsPinExt;
+    use std::ffi::{OsStr, OsString};
+    use std::process::Command;
+
+    let args: Vec<OsString> = ["build", "--release"].iter().map(OsString::from).collect();
+
+    let pinned: Vec<Pin<&OsStr>> = args.pin_args().collect();
+    assert_eq!(pinned.len(), 2);
+    for (pin, arg) in pinned.iter().zip(&args) {
+        assert_eq!(&**pin, arg.as_os_str());
+    }
+
+    let mut cmd = Command::new("cargo");
+    cmd.args(args.pin_args().map(Pin::into_inner));
+    assert_eq!(cmd.get_args().collect::<Vec<_>>(), ["build", "--release"]);
+}
+
+#[test]
+fn slice_pin_get_mut() {
+    use super::ext::SliceMutPinExt;
+
+    fn set(mut p: Pin<&mut u8>) {
+        *p = 0;
+    }
+
+    let mut buf = [1u8, 2, 3, 4];
+
+    set(buf.pin_get_mut(2).unwrap());
+    assert_eq!(buf, [1, 2, 0, 4]);
+
+    assert!(buf.pin_get_mut(10).is_none());
+}
+
+#[test]
+fn pinned_string_into_pinned_str() {
+    use super::pinned::IntoPin;
+
+    let mut pin: Pin<String> = String::from("pinned").into_pin();
+
+    // &Pin<String> to Pin<&str>
+    {
+        let s: Pin<&str> = (&pin).into_pin();
+        assert_eq!(&*s, "pinned");
+    }
+
+    // &mut Pin<String> to Pin<&mut str>
+    {
+        let mut s: Pin<&mut str> = (&mut pin).into_pin();
+        s.make_ascii_uppercase();
+    }
+    assert_eq!(&*pin, "PINNED");
+}
+
+#[test]
+fn enum_into_pinned_box() {
+    use super::pinned::IntoPin;
+
+    enum Message {
+        Text(String),
+        Bytes(Vec<u8>),
+    }
+
+    fn len(msg: Pin<Box<Message>>) -> usize {
+        match &*msg {
+            Message::Text(s) => {
+                let pin: Pin<&str> = s.into_pin();
+                pin.len()
+            }
+            Message::Bytes(b) => {
+                let pin: Pin<&[u8]> = b.into_pin();
+                pin.len()
+            }
+        }
+    }
+
+    assert_eq!(len(Message::Text(String::from("hi")).into_pin()), 2);
+    assert_eq!(len(Message::Bytes(vec![1, 2, 3]).into_pin()), 3);
+}
+
+#[test]
+fn hashmap_pin_values_mut() {
+    use super::ext::MapPinExt;
+    use std::collections::{BTreeMap, HashMap};
+
+    fn bump(mut p: Pin<&mut u32>) {
+        *p += 1;
+    }
+
+    let mut map = HashMap::new();
+    map.insert("a", 1u32);
+    map.insert("b", 2);
+
+    map.pin_values_mut().for_each(bump);
+    assert_eq!((map["a"], map["b"]), (2, 3));
+
+    let mut empty: BTreeMap<u8, u32> = BTreeMap::new();
+    assert_eq!(empty.pin_values_mut().count(), 0);
+}
+
+#[test]
+fn boxed_any_downcast_pin() {
+    use super::ext::AnyPinExt;
+    use std::any::Any;
+
+    let erased: Box<dyn Any> = Box::new(String::from("plugin"));
+    let erased = match erased.downcast_pin::<u32>() {
+        Ok(_) => panic!("downcast to the wrong type"),
+        Err(original) => original,
+    };
+
+    let pin: Pin<Box<String>> = erased.downcast_pin().unwrap();
+    assert_eq!(&**pin, "plugin");
+
+    let send: Box<dyn Any + Send> = Box::new(1u8);
+    assert!(send.downcast_pin::<u8>().is_ok());
+}
+
+#[test]
+fn vecdeque_pin_ends() {
+    use super::ext::DequePinExt;
+    use std::collections::VecDeque;
+
+    let mut queue: VecDeque<String> = VecDeque::new();
+    assert!(queue.pin_front().is_none());
+    assert!(queue.pin_back_mut().is_none());
+
+    queue.push_back(String::from("head"));
+    queue.push_back(String::from("tail"));
+
+    assert_eq!(&**queue.pin_front().unwrap(), "head");
+    queue.pin_back_mut().unwrap().push('!');
+    queue.pin_front_mut().unwrap().clear();
+
+    assert_eq!(queue, ["", "tail!"]);
+    assert_eq!(&**queue.pin_back().unwrap(), "tail!");
+}
+
+#[test]
+fn bytes_try_into_pinned_str() {
+    use super::TryIntoPin;
+
+    let bytes: &[u8] = "grüße".as_bytes();
+    let pin: Pin<&str> = bytes.try_into_pin().unwrap();
+    assert_eq!(&*pin, "grüße");
+    assert_eq!(pin.as_ptr(), bytes.as_ptr());
+
+    let invalid: &[u8] = &[b'a', 0xc3];
+    let err = TryIntoPin::<&str>::try_into_pin(invalid).unwrap_err();
+    assert_eq!(err.valid_up_to(), 1);
+}
+
+#[test]
+fn atomic_ptr_ref_into_pin() {
+    use super::IntoPin;
+    use std::ptr;
+    use std::sync::atomic::{AtomicPtr, Ordering};
+
+    let mut value = 5u8;
+    let atomic = AtomicPtr::new(ptr::null_mut::<u8>());
+
+    let pin: Pin<&AtomicPtr<u8>> = (&atomic).into_pin();
+    let swapped = pin.compare_exchange(
+        ptr::null_mut(),
+        &mut value,
+        Ordering::AcqRel,
+        Ordering::Acquire,
+    );
+    assert_eq!(swapped, Ok(ptr::null_mut()));
+    assert!(pin
+        .compare_exchange(ptr::null_mut(), ptr::null_mut(), Ordering::AcqRel, Ordering::Acquire)
+        .is_err());
+
+    assert_eq!(atomic.load(Ordering::Acquire), &mut value as *mut u8);
+}
+
+#[test]
+fn pin_builder_assembles_before_pinning() {
+    use super::PinBuilder;
+    use std::marker::PhantomPinned;
+
+    #[derive(Default)]
+    struct Parser {
+        input: Vec<u8>,
+        pos: usize,
+        _pin: PhantomPinned,
+    }
+
+    let builder = PinBuilder::<Parser>::new().set(|p| p.input.extend_from_slice(b"abc"));
+    let parser: Pin<Box<Parser>> = builder.set(|p| p.pos = 1).build();
+
+    assert_eq!(parser.input, b"abc");
+    assert_eq!(parser.pos, 1);
+
+    let pin = PinBuilder::from_value(vec![1, 2]).set(|v| v.push(3)).build();
+    assert_eq!(*pin, [1, 2, 3]);
+}
+
+#[test]
+fn vec_take_pin_leaves_empty() {
+    use super::ext::VecPinExt;
+
+    let mut acc = Vec::new();
+    acc.push(String::from("a"));
+    acc.push(String::from("b"));
+    let ptr = acc.as_ptr();
+
+    let batch: Pin<Vec<String>> = acc.take_pin();
+    assert_eq!(*batch, ["a", "b"]);
+    assert_eq!(batch.as_ptr(), ptr);
+    assert!(acc.is_empty());
+
+    acc.push(String::from("c"));
+    assert_eq!(acc.take_pin().len(), 1);
+}
+
+#[cfg(all(unix, feature = "unix_bytes"))]
+#[test]
+fn osstring_into_pinned_bytes() {
+    use super::pinned::IntoPin;
+    use std::ffi::OsString;
+    use std::os::unix::ffi::OsStringExt;
+
+    let os = OsString::from_vec(vec![b'f', 0xff, b'o']);
+    let ptr = os.as_encoded_bytes().as_ptr();
+
+    let pin: Pin<Vec<u8>> = os.into_pin();
+    assert_eq!(*pin, [b'f', 0xff, b'o']);
+    assert_eq!(pin.as_ptr(), ptr);
+}
+
+#[test]
+fn pin_field_of_tuple_ref() {
+    let tuple = (1u8, 2u16, 3u32);
+    let t: &(u8, u16, u32) = &tuple;
+
+    let first: Pin<&u8> = pin_field!(t, 0);
+    let last: Pin<&u32> = pin_field!(t, 2);
+    assert_eq!(*first, 1);
+    assert_eq!(*last, 3);
+
+    struct Named {
+        len: usize,
+    }
+    let named = Named { len: 4 };
+    assert_eq!(*pin_field!(&named, len), 4);
+}
+
+#[test]
+fn bytes_pin_align_to_u32() {
+    use super::ext::BytesPinExt;
+
+    let array = [1u8, 0, 0, 0, 2, 0, 0, 0, 3];
+
+    for start in 0..4 {
+        let bytes: &[u8] = &array[start..];
+        let (prefix, words, suffix) = unsafe { bytes.pin_align_to::<u32>() };
+        assert_eq!(prefix.len() + words.len() * 4 + suffix.len(), bytes.len());
+
+        let mut reassembled = prefix.to_vec();
+        for word in words.iter() {
+            reassembled.extend_from_slice(&word.to_ne_bytes());
+        }
+        reassembled.extend_from_slice(&suffix);
+        assert_eq!(reassembled, bytes);
+    }
+}
+
+#[test]
+fn boxed_str_into_pinned_arc_and_rc() {
+    use super::{box_into_pin_arc, box_into_pin_rc};
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    let interned: Box<str> = "symbol".into();
+    let pin: Pin<Arc<str>> = box_into_pin_arc(interned);
+    let shared = Pin::clone(&pin);
+    assert_eq!(&*shared, "symbol");
+    assert_eq!(Arc::strong_count(&Pin::into_inner(pin)), 2);
+
+    let interned: Box<str> = "local".into();
+    let pin: Pin<Rc<str>> = box_into_pin_rc(interned);
+    assert_eq!(&*pin, "local");
+}
+
+#[test]
+fn map_into_pin_entries_boxed() {
+    use super::ext::MapPinExt;
+    use std::collections::{BTreeMap, HashMap};
+
+    let mut map = HashMap::new();
+    map.insert("one", 1);
+    map.insert("two", 2);
+    map.insert("three", 3);
+
+    let entries: Pin<Box<[(&str, u32)]>> = map.into_pin_entries_boxed();
+    assert_eq!(entries.len(), 3);
+    for pair in &[("one", 1), ("two", 2), ("three", 3)] {
+        assert!(entries.contains(pair));
+    }
+
+    let sorted: BTreeMap<u8, char> = [(2, 'b'), (1, 'a')].iter().cloned().collect();
+    assert_eq!(*sorted.into_pin_entries_boxed(), [(1, 'a'), (2, 'b')]);
+}
+
+#[test]
+fn slice_pin_array_chunks() {
+    use super::ext::SliceMutPinExt;
+
+    let mut bytes = [1u8, 2, 3, 4, 5, 6, 7, 8];
+
+    let mut count = 0;
+    for mut chunk in (&mut bytes[..]).pin_array_chunks::<4>() {
+        let arr: &mut [u8; 4] = &mut chunk;
+        arr.reverse();
+        count += 1;
+    }
+    assert_eq!(count, 2);
+    assert_eq!(bytes, [4, 3, 2, 1, 8, 7, 6, 5]);
+    assert!((&mut bytes[..]).pin_array_chunks_remainder::<4>().is_empty());
+
+    let mut odd = [1u8, 2, 3, 4, 5];
+    assert_eq!((&mut odd[..]).pin_array_chunks::<2>().count(), 2);
+    let mut rest = (&mut odd[..]).pin_array_chunks_remainder::<2>();
+    rest[0] = 0;
+    assert_eq!(odd, [1, 2, 3, 4, 0]);
+}
+
+#[test]
+fn cell_value_pinned_by_box() {
+    use super::pinned::IntoPin;
+    use std::cell::Cell;
+
+    let pin: Pin<Box<Cell<u32>>> = Cell::new(1).into_pin();
+    Pin::get_ref(pin.as_ref()).set(5);
+    assert_eq!(pin.get(), 5);
+
+    let cell = Cell::new(7u32);
+    let pin: Pin<&Cell<u32>> = (&cell).into_pin();
+    Pin::get_ref(pin).set(8);
+    assert_eq!(cell.get(), 8);
+}
+
+#[test]
+fn transpose_option_pin_both_branches() {
+    use super::transpose_option_pin;
+
+    let mut n = 1u32;
+    let mut pin: Pin<&mut u32> = transpose_option_pin(Some(&mut n)).unwrap();
+    *pin += 1;
+    assert_eq!(n, 2);
+
+    let none: Option<&mut u32> = None;
+    assert!(transpose_option_pin(none).is_none());
+
+    let shared: Option<Pin<&u32>> = transpose_option_pin(Some(&n));
+    assert_eq!(shared.as_deref(), Some(&2));
+}
+
+#[test]
+fn pin_project_fields_of_struct() {
+    struct MyStruct {
+        buf: Vec<u8>,
+        count: usize,
+        _tag: (u8, char),
+    }
+
+    let mut s = MyStruct {
+        buf: Vec::new(),
+        count: 0,
+        _tag: (1, 'x'),
+    };
+    let mut pin: Pin<&mut MyStruct> = Pin::new(&mut s);
+
+    let mut buf: Pin<&mut Vec<u8>> = pin_project_fields!(pin, buf);
+    buf.push(1);
+    let mut count: Pin<&mut usize> = pin_project_fields!(pin, count);
+    *count += 1;
+
+    let (mut buf, mut count) = pin_project_fields!(pin, buf, count);
+    buf.push(2);
+    *count += buf.len();
+
+    assert_eq!(s.buf, [1, 2]);
+    assert_eq!(s.count, 3);
+}
+
+#[test]
+fn vec_try_into_pinned_cstring() {
+    use super::TryIntoPin;
+    use std::ffi::CString;
+
+    let pin: Pin<CString> = b"ffi".to_vec().try_into_pin().unwrap();
+    assert_eq!(pin.to_bytes_with_nul(), b"ffi\0");
+
+    let err = TryIntoPin::<CString>::try_into_pin(vec![b'a', 0, b'b']).unwrap_err();
+    assert_eq!(err.nul_position(), 1);
+    assert_eq!(err.into_vec(), [b'a', 0, b'b']);
+}
+
+#[test]
+fn cow_str_into_pinned_string() {
+    use super::pinned::IntoPin;
+    use std::borrow::Cow;
+
+    let owned = String::from("owned");
+    let ptr = owned.as_ptr();
+    let cow: Cow<str> = Cow::Owned(owned);
+    let pin: Pin<String> = cow.into_pin();
+    assert_eq!(&*pin, "owned");
+    assert_eq!(pin.as_ptr(), ptr);
+
+    let borrowed = "borrowed";
+    let cow: Cow<str> = Cow::Borrowed(borrowed);
+    let pin: Pin<String> = cow.into_pin();
+    assert_eq!(&*pin, "borrowed");
+    assert_ne!(pin.as_ptr(), borrowed.as_ptr());
+}
+
+#[test]
+fn btreemap_pin_range_values_mut() {
+    use super::ext::RangePinExt;
+    use std::collections::BTreeMap;
+
+    let mut map: BTreeMap<u8, u32> = (1..=5).map(|k| (k, u32::from(k))).collect();
+
+    for mut v in map.pin_range_values_mut(2..=4) {
+        *v *= 10;
+    }
+    assert_eq!(map.values().cloned().collect::<Vec<_>>(), [1, 20, 30, 40, 5]);
+
+    assert_eq!(map.pin_range_values_mut(6..).count(), 0);
+}
+
+#[test]
+fn pin_replace_returns_old_value() {
+    use super::pin_replace;
+
+    let mut slot = 1u32;
+    let old: Pin<Box<u32>> = pin_replace(&mut slot, 2);
+    assert_eq!(*old, 1);
+    assert_eq!(slot, 2);
+}
+
+#[test]
+fn boxed_slice_into_pin_iter() {
+    use super::ext::BoxedSlicePinExt;
+
+    let boxed: Box<[u32]> = Box::new([1, 2, 3]);
+    let pins: Vec<Pin<Box<u32>>> = boxed.into_pin_iter().collect();
+    assert_eq!(pins.len(), 3);
+    assert_eq!(pins.iter().map(|p| **p).collect::<Vec<_>>(), [1, 2, 3]);
+
+    let empty: Box<[u32]> = Box::new([]);
+    assert_eq!(empty.into_pin_iter().count(), 0);
+}
+
+#[test]
+fn pinned_arc_of_ref_into_pin() {
+    use super::pinned::IntoPin;
+    use std::sync::Arc;
+
+    let value = 7u32;
+    let shared: Pin<Arc<&u32>> = Pin::new(Arc::new(&value));
+    let other = Pin::clone(&shared);
+
+    let pin: Pin<&u32> = (&shared).into_pin();
+    assert_eq!(*pin, 7);
+    assert!(std::ptr::eq(pin.get_ref(), &value));
+    assert_eq!(**other, 7);
+}
+
+#[test]
+fn pin_zip_mut_columns() {
+    use super::pin_zip_mut;
+
+    let mut flags = [0u8, 1, 0];
+    let mut counts = [5u32, 6, 7];
+
+    for (mut flag, mut count) in pin_zip_mut(&mut flags, &mut counts) {
+        *count += u32::from(*flag);
+        *flag = 1;
+    }
+    assert_eq!(flags, [1, 1, 1]);
+    assert_eq!(counts, [5, 7, 7]);
+
+    let mut short = [1u8];
+    assert_eq!(pin_zip_mut(&mut short, &mut counts).count(), 1);
+}
+
+#[test]
+fn osstring_try_into_pinned_string() {
+    use super::TryIntoPin;
+    use std::ffi::OsString;
+
+    let pin: Pin<String> = OsString::from("config.toml").try_into_pin().unwrap();
+    assert_eq!(&*pin, "config.toml");
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+
+        let invalid = OsString::from_vec(vec![b'a', 0xff]);
+        let err = TryIntoPin::<String>::try_into_pin(invalid).unwrap_err();
+        assert_eq!(err.into_vec(), [b'a', 0xff]);
+    }
+}
+
+#[test]
+fn rc_refcell_pinned_guards() {
+    use super::ext::RefCellPinExt;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let shared = Rc::new(RefCell::new(vec![1u32]));
+    let other = Rc::clone(&shared);
+
+    {
+        let mut guard = shared.pin_borrow_mut();
+        guard.push(2);
+    }
+    assert_eq!(*other.pin_borrow(), [1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn rc_refcell_pin_borrow_while_mutably_borrowed() {
+    use super::ext::RefCellPinExt;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let shared = Rc::new(RefCell::new(0u32));
+    let _guard = shared.pin_borrow_mut();
+    let _ = shared.pin_borrow_mut();
+}
+
+#[test]
+fn pin_stack_or_heap_unpin_path() {
+    use super::{pin_heap, pin_stack_or_heap};
+
+    fn bump<T: Unpin + std::ops::AddAssign + From<u8>>(value: &mut T) {
+        let mut pin: Pin<&mut T> = pin_stack_or_heap(value);
+        *pin += T::from(1);
+    }
+
+    let mut n = 1u32;
+    let before = &n as *const u32;
+    bump(&mut n);
+    assert_eq!(n, 2);
+    assert_eq!(&*pin_stack_or_heap(&mut n) as *const u32, before);
+
+    let mut s = String::from("ab");
+    pin_stack_or_heap(&mut s[..]).make_ascii_uppercase();
+    assert_eq!(s, "AB");
+
+    assert_eq!(*pin_heap(3u8), 3);
+}
+
+#[test]
+fn io_slices_into_pin() {
+    use super::pinned::IntoPin;
+    use std::io::{IoSlice, IoSliceMut};
+
+    let buf = [1u8, 2, 3];
+    let slice = IoSlice::new(&buf);
+
+    let pin: Pin<&IoSlice> = (&slice).into_pin();
+    assert_eq!(pin.len(), 3);
+
+    let owned: Pin<IoSlice> = slice.into_pin();
+    assert_eq!(*owned, [1, 2, 3]);
+
+    let mut a = [0u8; 2];
+    let mut b = [0u8; 1];
+    let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+    {
+        let mut pin: Pin<&mut [IoSliceMut]> = (&mut bufs[..]).into_pin();
+        pin[1][0] = 9;
+    }
+    let mut owned: Pin<IoSliceMut> = IoSliceMut::new(&mut a).into_pin();
+    owned[0] = 7;
+    assert_eq!((a, b), ([7, 0], [9]));
+}
+
+#[test]
+fn pinned_box_pin_into() {
+    use super::ext::PinInto;
+
+    let small: Pin<Box<u8>> = Box::pin(200);
+    let wide: Pin<Box<u32>> = small.pin_into();
+    assert_eq!(*wide, 200);
+
+    let text: Pin<Box<&str>> = Box::pin("owned");
+    let owned: Pin<Box<String>> = text.pin_into();
+    assert_eq!(owned.as_str(), "owned");
+}
+
+#[test]
+fn slice_into_pin_windows() {
+    use super::ext::SlicePinExt;
+
+    let samples: &[u32] = &[1, 2, 3, 4];
+    let windows: Vec<Pin<&[u32]>> = samples.into_pin_windows(2).collect();
+    assert_eq!(windows.len(), 3);
+    assert_eq!(*windows[0], [1, 2]);
+    assert_eq!(*windows[2], [3, 4]);
+
+    assert_eq!(samples.into_pin_windows(5).count(), 0);
+}
+
+#[test]
+#[should_panic]
+fn slice_into_pin_windows_zero() {
+    use super::ext::SlicePinExt;
+
+    let samples: &[u32] = &[1, 2];
+    let _ = samples.into_pin_windows(0);
+}
+
+#[test]
+fn box_leak_pin_static() {
+    use super::ext::LeakPinExt;
+
+    // Leaks on purpose.
+    let mut counter: Pin<&'static mut u32> = Box::new(1u32).leak_pin();
+    *counter += 1;
+    assert_eq!(*counter, 2);
+
+    let name: Pin<&'static mut str> = String::from("global").into_boxed_str().leak_pin();
+    assert_eq!(&*name, "global");
+}
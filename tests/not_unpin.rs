@@ -0,0 +1,7 @@
+// The expected compiler output is recorded with a stable compiler, and can change between compiler releases.
+#[rustversion::attr(not(all(stable, since(1.95))), ignore)]
+#[test]
+fn not_unpin() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/not_unpin.rs");
+}
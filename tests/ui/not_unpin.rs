@@ -0,0 +1,21 @@
+extern crate pinpoint;
+
+use pinpoint::IntoPin;
+use std::marker::PhantomPinned;
+use std::pin::Pin;
+
+struct SelfReferential {
+    _pinned: PhantomPinned,
+}
+
+// `T` is not known to be `Unpin`, and may hold a `PhantomPinned` like `SelfReferential`.
+fn pin_ref<T>(value: &T) -> Pin<&T> {
+    value.into_pin()
+}
+
+fn main() {
+    let value = SelfReferential {
+        _pinned: PhantomPinned,
+    };
+    let _pin = pin_ref(&value);
+}